@@ -1,13 +1,15 @@
 use std::hash::Hasher;
 
-use serde::Deserialize;
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
-use super::layers::*;
+use crate::{ParseOptions, Winding};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub struct Coordinate {
     pub lat: f64,
     pub lon: f64,
+    /// The third ordinate of the position, if the source provided one
+    pub elevation: Option<f64>,
 }
 
 impl std::hash::Hash for Coordinate {
@@ -26,30 +28,163 @@ impl<'de> Deserialize<'de> for Coordinate {
     {
         // Lat and lon are reversed in the GeoJSON data
         let v: Vec<f64> = Deserialize::deserialize(deserializer)?;
-        Ok(Coordinate { lat: v[1], lon: v[0] })
+        match v[..] {
+            [lon, lat] => Ok(Coordinate {
+                lat,
+                lon,
+                elevation: None,
+            }),
+            [lon, lat, elevation, ..] => Ok(Coordinate {
+                lat,
+                lon,
+                elevation: Some(elevation),
+            }),
+            _ => Err(D::Error::invalid_length(
+                v.len(),
+                &"a position with at least two elements",
+            )),
+        }
     }
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct FeatureCollection<T> {
-    #[serde(rename = "type")]
-    pub type_: String,
-    #[serde(rename = "features")]
-    pub features: Vec<T>,
+impl Serialize for Coordinate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.elevation {
+            Some(elevation) => [self.lon, self.lat, elevation].serialize(serializer),
+            None => [self.lon, self.lat].serialize(serializer),
+        }
+    }
+}
+
+/// The geometry of a point feature
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum PointGeometry {
+    Point(Coordinate),
+}
+
+impl PointGeometry {
+    /// The elevation of the point, if it has one
+    pub fn elevation(&self) -> Option<f64> {
+        match self {
+            PointGeometry::Point(coordinate) => coordinate.elevation,
+        }
+    }
+}
+
+/// Collects the elevations of a sequence of positions, or None if any of them lacks one
+fn elevations(coordinates: &[Coordinate]) -> Option<Vec<f64>> {
+    coordinates.iter().map(|coordinate| coordinate.elevation).collect()
+}
+
+/// The geometry of a line feature, which may be split into several parts
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum LineStringGeometry {
+    LineString(Vec<Coordinate>),
+    MultiLineString(Vec<Vec<Coordinate>>),
+}
+
+impl LineStringGeometry {
+    /// The elevations of the vertices of each part, or None unless every vertex has one
+    pub fn elevations(&self) -> Option<Vec<Vec<f64>>> {
+        match self {
+            LineStringGeometry::LineString(line) => Some(vec![elevations(line)?]),
+            LineStringGeometry::MultiLineString(lines) => lines.iter().map(|line| elevations(line)).collect(),
+        }
+    }
+}
+
+/// Closes a ring if it is open, and reverses it if it is not wound in the given direction, if any. Returns whether the
+/// ring was reversed.
+fn orient_ring(ring: &mut Vec<Coordinate>, winding: Option<Winding>) -> bool {
+    if let (Some(&first), Some(&last)) = (ring.first(), ring.last()) {
+        if (first.lat, first.lon) != (last.lat, last.lon) {
+            ring.push(first);
+        }
+    }
+
+    // Twice the signed area, positive when the ring is counter-clockwise
+    let area: f64 = ring.windows(2).map(|w| w[0].lon * w[1].lat - w[1].lon * w[0].lat).sum();
+
+    let reverse = match winding {
+        Some(Winding::Clockwise) => area > 0.0,
+        Some(Winding::CounterClockwise) => area < 0.0,
+        None => false,
+    };
+
+    if reverse {
+        ring.reverse();
+    }
+
+    reverse
+}
+
+/// Orients the exterior ring of a polygon in the given direction and its holes in the opposite direction, or only
+/// closes its rings if no direction is given. Returns whether any ring was reversed.
+fn orient_polygon(rings: &mut [Vec<Coordinate>], winding: Option<Winding>) -> bool {
+    let hole_winding = winding.map(|winding| match winding {
+        Winding::Clockwise => Winding::CounterClockwise,
+        Winding::CounterClockwise => Winding::Clockwise,
+    });
+
+    let mut reoriented = false;
+
+    for (index, ring) in rings.iter_mut().enumerate() {
+        reoriented |= orient_ring(ring, if index == 0 { winding } else { hole_winding });
+    }
+
+    reoriented
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
-pub struct Geometry<T> {
-    #[serde(rename = "type")]
-    pub type_: String,
-    #[serde(rename = "coordinates")]
-    pub coordinates: T,
+/// The geometry of a polygon feature, which may be split into several parts
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum PolygonGeometry {
+    Polygon(Vec<Vec<Coordinate>>),
+    MultiPolygon(Vec<Vec<Vec<Coordinate>>>),
+}
+
+impl PolygonGeometry {
+    /// The elevations of the vertices of each ring of each part, or None unless every vertex has one
+    pub fn elevations(&self) -> Option<Vec<Vec<Vec<f64>>>> {
+        let polygon = |rings: &Vec<Vec<Coordinate>>| rings.iter().map(|ring| elevations(ring)).collect();
+
+        match self {
+            PolygonGeometry::Polygon(rings) => Some(vec![polygon(rings)?]),
+            PolygonGeometry::MultiPolygon(polygons) => polygons.iter().map(polygon).collect(),
+        }
+    }
+
+    /// Orients every polygon of the geometry, returning whether any ring was reversed
+    pub fn orient(&mut self, winding: Option<Winding>) -> bool {
+        match self {
+            PolygonGeometry::Polygon(rings) => orient_polygon(rings, winding),
+            PolygonGeometry::MultiPolygon(polygons) => {
+                let mut reoriented = false;
+
+                for rings in polygons {
+                    reoriented |= orient_polygon(rings, winding);
+                }
+
+                reoriented
+            },
+        }
+    }
+}
+
+/// A feature which may need its geometry normalized after it has been deserialized
+pub trait Feature {
+    fn normalize(&mut self, _options: &ParseOptions) {}
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Point<T> {
     #[serde(rename = "geometry")]
-    pub geometry: Geometry<Coordinate>,
+    pub geometry: PointGeometry,
     #[serde(rename = "properties")]
     pub properties: T,
 }
@@ -57,83 +192,48 @@ pub struct Point<T> {
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Polygon<T> {
     #[serde(rename = "geometry")]
-    pub geometry: Geometry<Vec<Vec<Coordinate>>>,
+    pub geometry: PolygonGeometry,
     #[serde(rename = "properties")]
     pub properties: T,
+    /// Whether any ring had to be reversed when the geometry was normalized
+    #[serde(skip)]
+    pub reoriented: bool,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct LineString<T> {
     #[serde(rename = "geometry")]
-    pub geometry: Geometry<Vec<Coordinate>>,
+    pub geometry: LineStringGeometry,
     #[serde(rename = "properties")]
     pub properties: T,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct AirportMapData {
-    #[serde(rename = "aerodromereferencepoint")]
-    pub aerodrome_reference_point: FeatureCollection<Point<AerodromeReferencePoint>>,
-    #[serde(rename = "apronelement")]
-    pub apron_element: FeatureCollection<Polygon<ApronElement>>,
-    #[serde(rename = "blastpad")]
-    pub blastpad: FeatureCollection<Polygon<Blastpad>>,
-    #[serde(rename = "constructionarea")]
-    pub construction_area: FeatureCollection<Polygon<ConstructionArea>>,
-    #[serde(rename = "deicingarea")]
-    pub deicing_area: FeatureCollection<Polygon<DeicingArea>>,
-    #[serde(rename = "finalapproachandtakeoffarea")]
-    pub final_approach_and_takeoff_area: FeatureCollection<Polygon<FinalApproachAndTakeoffArea>>,
-    #[serde(rename = "frequencyarea")]
-    pub frequency_area: FeatureCollection<Polygon<FrequencyArea>>,
-    #[serde(rename = "hotspot")]
-    pub hotspot: FeatureCollection<Polygon<Hotspot>>,
-    #[serde(rename = "landandholdshortoperationlocation")]
-    pub land_and_hold_short_operation_location: FeatureCollection<LineString<LandAndHoldShortOperationLocation>>,
-    #[serde(rename = "paintedcenterline")]
-    pub painted_centerline: FeatureCollection<LineString<PaintedCenterline>>,
-    #[serde(rename = "parkingstandarea")]
-    pub parking_stand_area: FeatureCollection<Polygon<ParkingStandArea>>,
-    #[serde(rename = "parkingstandlocation")]
-    pub parking_stand_location: FeatureCollection<Point<ParkingStandLocation>>,
-    #[serde(rename = "runwaydisplacedarea")]
-    pub runway_displaced_area: FeatureCollection<Polygon<RunwayDisplacedArea>>,
-    #[serde(rename = "runwayelement")]
-    pub runway_element: FeatureCollection<Polygon<RunwayElement>>,
-    #[serde(rename = "runwayexitline")]
-    pub runway_exit_line: FeatureCollection<LineString<RunwayExitLine>>,
-    #[serde(rename = "runwayintersection")]
-    pub runway_intersection: FeatureCollection<Polygon<RunwayIntersection>>,
-    #[serde(rename = "runwaymarking")]
-    pub runway_marking: FeatureCollection<Polygon<RunwayMarking>>,
-    #[serde(rename = "runwayshoulder")]
-    pub runway_shoulder: FeatureCollection<Polygon<RunwayShoulder>>,
-    #[serde(rename = "runwaythreshold")]
-    pub runway_threshold: FeatureCollection<Point<RunwayThreshold>>,
-    #[serde(rename = "serviceroad")]
-    pub service_road: FeatureCollection<Polygon<ServiceRoad>>,
-    #[serde(rename = "standguidanceline")]
-    pub stand_guidance_line: FeatureCollection<LineString<StandGuidanceLine>>,
-    #[serde(rename = "stopway")]
-    pub stopway: FeatureCollection<Polygon<Stopway>>,
-    #[serde(rename = "taxiwayelement")]
-    pub taxiway_element: FeatureCollection<Polygon<TaxiwayElement>>,
-    #[serde(rename = "taxiwayguidanceline")]
-    pub taxiway_guidance_line: FeatureCollection<LineString<TaxiwayGuidanceLine>>,
-    #[serde(rename = "taxiwayholdingposition")]
-    pub taxiway_holding_position: FeatureCollection<LineString<TaxiwayHoldingPosition>>,
-    #[serde(rename = "taxiwayintersectionmarking")]
-    pub taxiway_intersection_marking: FeatureCollection<LineString<TaxiwayIntersectionMarking>>,
-    #[serde(rename = "taxiwayshoulder")]
-    pub taxiway_shoulder: FeatureCollection<Polygon<TaxiwayShoulder>>,
-    #[serde(rename = "touchdownliftoffarea")]
-    pub touchdown_liftoff_area: FeatureCollection<Polygon<TouchdownLiftoffArea>>,
-    #[serde(rename = "verticallinestructure")]
-    pub vertical_line_structure: FeatureCollection<LineString<VerticalLineStructure>>,
-    #[serde(rename = "verticalpointstructure")]
-    pub vertical_point_structure: FeatureCollection<Point<VerticalPointStructure>>,
-    #[serde(rename = "verticalpolygonalstructure")]
-    pub vertical_polygonal_structure: FeatureCollection<Polygon<VerticalPolygonalStructure>>,
-    #[serde(rename = "water")]
-    pub water: FeatureCollection<Polygon<Water>>,
+impl<T> Feature for Point<T> {}
+
+impl<T> Feature for LineString<T> {}
+
+impl<T> Feature for Polygon<T> {
+    fn normalize(&mut self, options: &ParseOptions) { self.reoriented = self.geometry.orient(options.winding); }
 }
+
+/// Serializes a feature as a GeoJSON Feature object
+macro_rules! serialize_feature {
+    ($feature:ident) => {
+        impl<T: Serialize> Serialize for $feature<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut feature = serializer.serialize_struct("Feature", 3)?;
+                feature.serialize_field("type", "Feature")?;
+                feature.serialize_field("geometry", &self.geometry)?;
+                feature.serialize_field("properties", &self.properties)?;
+                feature.end()
+            }
+        }
+    };
+}
+
+serialize_feature!(Point);
+serialize_feature!(LineString);
+serialize_feature!(Polygon);
@@ -1,8 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::enums::*;
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct AerodromeReferencePoint {
     pub id: u64,
     pub idarpt: String,
@@ -11,7 +11,7 @@ pub struct AerodromeReferencePoint {
     pub elev: f64,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ApronElement {
     pub id: u64,
     pub idapron: Option<String>,
@@ -19,13 +19,13 @@ pub struct ApronElement {
     pub status: Status,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct Blastpad {
     pub id: u64,
     pub idthr: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ConstructionArea {
     pub id: u64,
     pub pstdate: String,
@@ -33,7 +33,7 @@ pub struct ConstructionArea {
     pub piocdate: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct DeicingArea {
     pub id: u64,
     pub idbase: Option<String>,
@@ -43,39 +43,39 @@ pub struct DeicingArea {
     pub restacn: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct FinalApproachAndTakeoffArea {
     pub id: u64,
     pub idrwy: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct FrequencyArea {
     pub id: u64,
     pub frq: f64,
     pub station: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct Hotspot {
     pub id: u64,
     pub idhot: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct LandAndHoldShortOperationLocation {
     pub id: u64,
     pub idp: String,
     pub idthr: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct PaintedCenterline {
     pub id: u64,
     pub idrwy: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ParkingStandArea {
     pub id: u64,
     pub idstd: Option<String>,
@@ -89,7 +89,7 @@ pub struct ParkingStandArea {
     pub termref: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ParkingStandLocation {
     pub id: u64,
     pub idstd: Option<String>,
@@ -97,7 +97,7 @@ pub struct ParkingStandLocation {
     pub termref: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayDisplacedArea {
     pub id: u64,
     pub idthr: String,
@@ -105,7 +105,7 @@ pub struct RunwayDisplacedArea {
     pub surftype: SurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayElement {
     pub id: u64,
     pub idrwy: String,
@@ -114,7 +114,7 @@ pub struct RunwayElement {
     pub surftype: SurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayExitLine {
     pub id: u64,
     pub idlin: Option<String>,
@@ -124,20 +124,20 @@ pub struct RunwayExitLine {
     pub style: Style,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayIntersection {
     pub id: u64,
     pub idrwi: String,
     pub surftype: SurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayMarking {
     pub id: u64,
     pub idrwy: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayShoulder {
     pub id: u64,
     pub idrwy: String,
@@ -145,7 +145,7 @@ pub struct RunwayShoulder {
     pub gsurftyp: GroundSurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct RunwayThreshold {
     pub id: u64,
     pub idthr: String,
@@ -164,14 +164,14 @@ pub struct RunwayThreshold {
     pub vasis: PapiVasi,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ServiceRoad {
     pub id: u64,
     pub gsurftyp: GroundSurfaceType,
     pub idbase: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct StandGuidanceLine {
     pub id: u64,
     pub idstd: Option<String>,
@@ -182,15 +182,15 @@ pub struct StandGuidanceLine {
     pub termref: Option<String>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct Stopway {
     pub id: u64,
     pub idthr: String,
-    pub status: f64,
+    pub status: Status,
     pub surftype: SurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TaxiwayElement {
     pub id: u64,
     pub idlin: Option<String>,
@@ -199,7 +199,7 @@ pub struct TaxiwayElement {
     pub bridge: Bridge,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TaxiwayGuidanceLine {
     pub id: u64,
     pub idlin: Option<String>,
@@ -209,7 +209,7 @@ pub struct TaxiwayGuidanceLine {
     pub style: Style,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TaxiwayHoldingPosition {
     pub id: u64,
     pub idp: Option<String>,
@@ -218,27 +218,27 @@ pub struct TaxiwayHoldingPosition {
     pub catstop: CatStop,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TaxiwayIntersectionMarking {
     pub id: u64,
     pub idlin: String,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TaxiwayShoulder {
     pub id: u64,
     pub gsurftyp: GroundSurfaceType,
     pub status: Status,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct TouchdownLiftoffArea {
     pub id: u64,
     pub idrwy: Option<String>,
     pub surftype: SurfaceType,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct VerticalLineStructure {
     pub id: u64,
     pub linsttyp: LineStructureType,
@@ -249,7 +249,7 @@ pub struct VerticalLineStructure {
     pub marking: Conformance,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct VerticalPointStructure {
     pub id: u64,
     pub pntsttyp: PointStructureType,
@@ -261,7 +261,7 @@ pub struct VerticalPointStructure {
     pub marking: Conformance,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct VerticalPolygonalStructure {
     pub id: u64,
     pub ident: Option<String>,
@@ -271,5 +271,7 @@ pub struct VerticalPolygonalStructure {
     pub elev: f64,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct Water {}
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct Water {
+    pub id: u64,
+}
@@ -0,0 +1,351 @@
+use std::{cell::OnceCell, fmt};
+
+use geo::{Coord, EuclideanDistance, HaversineDistance, Line, Point};
+
+use crate::{
+    holding_position::{known_target, InferredHoldingTarget},
+    output_types::{HoldingPointTarget, RunwayDesignator, RunwayId},
+    taxi_graph::{EdgeId, EdgeKind, NodeId, TaxiGraph},
+    taxi_route::{RouteLocation, TaxiRoute},
+    Airport,
+};
+
+/// Where a taxi clearance ends
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClearanceLimit {
+    Stand(String),
+    /// A runway holding point, named by its taxiway and the runway it protects where they are known
+    HoldingPoint {
+        taxiway_id: Option<String>,
+        runway: Option<RunwayDesignator>,
+    },
+    Runway(RunwayDesignator),
+}
+
+impl fmt::Display for ClearanceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClearanceLimit::Stand(stand_id) => write!(f, "stand {stand_id}"),
+            ClearanceLimit::HoldingPoint { taxiway_id, runway } => {
+                f.write_str("holding point")?;
+
+                if let Some(taxiway_id) = taxiway_id {
+                    write!(f, " {taxiway_id}")?;
+                }
+
+                match runway {
+                    Some(runway) => write!(f, " runway {runway}"),
+                    None => Ok(()),
+                }
+            },
+            ClearanceLimit::Runway(runway) => write!(f, "runway {runway}"),
+        }
+    }
+}
+
+/// A step of a taxi clearance
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClearanceInstruction {
+    /// Taxi along a taxiway
+    Taxiway(String),
+    /// Stop at the holding position before a runway
+    HoldShort(RunwayDesignator),
+}
+
+/// A taxi clearance as it would be given by ATC, such as "Taxi to holding point A runway 09 via B, K, hold short of
+/// runway 27R"
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxiClearance {
+    /// Where the clearance ends, if the route ends at a stand, holding point or runway
+    pub limit: Option<ClearanceLimit>,
+    pub instructions: Vec<ClearanceInstruction>,
+}
+
+impl fmt::Display for TaxiClearance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Taxi")?;
+
+        if let Some(limit) = &self.limit {
+            write!(f, " to {limit}")?;
+        }
+
+        let mut via = false;
+
+        for instruction in &self.instructions {
+            match instruction {
+                ClearanceInstruction::Taxiway(taxiway_id) if !via => {
+                    via = true;
+                    write!(f, " via {taxiway_id}")?;
+                },
+                ClearanceInstruction::Taxiway(taxiway_id) => write!(f, ", {taxiway_id}")?,
+                ClearanceInstruction::HoldShort(runway) => write!(f, ", hold short of runway {runway}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The designator a runway is named by at a location: the end in use if one is, or otherwise the end nearest the
+/// location
+fn spoken_designator(
+    airport: &Airport, runway_id: &RunwayId, location: Coord, runways_in_use: &[RunwayDesignator],
+) -> RunwayDesignator {
+    if let Some(in_use) = [&runway_id.first, &runway_id.second]
+        .into_iter()
+        .find(|designator| runways_in_use.contains(designator))
+    {
+        return in_use.clone();
+    }
+
+    let runway = airport
+        .runways()
+        .iter()
+        .find(|runway| runway.runway_id.same_runway(runway_id));
+
+    let Some((runway, centerline)) = runway.and_then(|runway| Some((runway, runway.centerline?))) else {
+        return runway_id.first.clone();
+    };
+
+    let distance = |coord: Coord| Point::from(coord).haversine_distance(&Point::from(location));
+
+    match distance(centerline.end) < distance(centerline.start) {
+        true => runway.ends[1].designator.clone(),
+        false => runway.ends[0].designator.clone(),
+    }
+}
+
+/// Builds up the instructions of a clearance while following a route
+struct Composer<'a> {
+    airport: &'a Airport,
+    graph: &'a TaxiGraph,
+    runways_in_use: &'a [RunwayDesignator],
+    /// The node where the route ends at its holding point, whose holding positions are the limit rather than
+    /// instructions
+    limit_node: Option<NodeId>,
+    /// The runway the route ends on, whose holding positions are covered by the limit
+    limit_runway: Option<RunwayId>,
+    instructions: Vec<ClearanceInstruction>,
+    /// The runways held short of which have not yet been entered
+    holding: Vec<RunwayId>,
+    /// The inferred targets of the holding positions, for those whose target is unknown
+    inferred: OnceCell<Vec<InferredHoldingTarget>>,
+}
+
+impl Composer<'_> {
+    fn enter_runway(&mut self, runway_id: &RunwayId) { self.holding.retain(|holding| !holding.same_runway(runway_id)); }
+
+    /// Whether travelling from one location to another brings the aircraft closer to a runway
+    fn approaches(&self, runway_id: &RunwayId, from: Coord, to: Coord) -> bool {
+        let centerline = self
+            .airport
+            .runways()
+            .iter()
+            .find(|runway| runway.runway_id.same_runway(runway_id))
+            .and_then(|runway| runway.centerline);
+
+        let Some(centerline) = centerline else {
+            return true;
+        };
+
+        let projection = self.graph.projection();
+        let centerline = Line::new(projection.project(centerline.start), projection.project(centerline.end));
+        let distance = |coord: Coord| Point::from(projection.project(coord)).euclidean_distance(&centerline);
+
+        distance(to) < distance(from)
+    }
+
+    /// Visits a node of the route, given where the route goes next
+    fn visit_node(&mut self, node: NodeId, next: Coord) {
+        let graph = self.graph;
+        let location = graph.node(node).location;
+
+        // Nodes on a runway centerline are where the route crosses or joins it
+        for (_, edge) in graph.edges_at(node).filter(|(_, edge)| edge.kind == EdgeKind::Runway) {
+            if let Some(runway_id) = &edge.runway_id {
+                self.enter_runway(runway_id);
+            }
+        }
+
+        if self.limit_node == Some(node) {
+            return;
+        }
+
+        for id in &graph.node(node).holding_position_ids {
+            let target = self
+                .airport
+                .taxiway_holding_positions
+                .iter()
+                .find(|position| position.id == *id)
+                .and_then(|position| known_target(self.airport, &self.inferred, position));
+
+            let Some(HoldingPointTarget::Runway(runway_id)) = target else {
+                continue;
+            };
+
+            let covered = self
+                .holding
+                .iter()
+                .chain(&self.limit_runway)
+                .any(|held| held.same_runway(&runway_id));
+
+            if covered {
+                continue;
+            }
+
+            // Holding positions passed on the way off a runway need no instruction
+            if !self.approaches(&runway_id, location, next) {
+                continue;
+            }
+
+            self.instructions
+                .push(ClearanceInstruction::HoldShort(spoken_designator(
+                    self.airport,
+                    &runway_id,
+                    location,
+                    self.runways_in_use,
+                )));
+            self.holding.push(runway_id);
+        }
+    }
+
+    fn travel_edge(&mut self, edge: EdgeId) {
+        let edge = self.graph.edge(edge);
+
+        if let (EdgeKind::Runway, Some(runway_id)) = (edge.kind, &edge.runway_id) {
+            self.enter_runway(runway_id);
+        }
+
+        let Some(taxiway_id) = &edge.taxiway_id else {
+            return;
+        };
+
+        let last_taxiway = self
+            .instructions
+            .iter()
+            .rev()
+            .find_map(|instruction| match instruction {
+                ClearanceInstruction::Taxiway(taxiway_id) => Some(taxiway_id),
+                ClearanceInstruction::HoldShort(_) => None,
+            });
+
+        if last_taxiway != Some(taxiway_id) {
+            self.instructions
+                .push(ClearanceInstruction::Taxiway(taxiway_id.clone()));
+        }
+    }
+}
+
+impl TaxiRoute {
+    /// Describes the route as a taxi clearance to `destination`, the location the route was found to.
+    ///
+    /// Consecutive edges are collapsed into the taxiways they lie on, and the aircraft is told to hold short at every
+    /// holding position for a runway it comes to before entering that runway. Holding positions whose target is unknown
+    /// in the data are taken to protect the runway inferred from their geometry. Runways are named by the end in
+    /// `runways_in_use` where one is given, and otherwise by the end nearest the holding position.
+    pub fn clearance(
+        &self, airport: &Airport, graph: &TaxiGraph, destination: &RouteLocation, runways_in_use: &[RunwayDesignator],
+    ) -> TaxiClearance {
+        let end = self
+            .geometry
+            .0
+            .last()
+            .copied()
+            .unwrap_or(airport.aerodrome_reference_point.location);
+
+        let limit_runway = match destination {
+            RouteLocation::RunwayExit(_) => self.nodes.last().and_then(|&node| {
+                graph
+                    .edges_at(node)
+                    .find_map(|(_, edge)| edge.runway_id.clone().filter(|_| edge.kind == EdgeKind::Runway))
+            }),
+            _ => None,
+        };
+
+        let inferred = OnceCell::new();
+
+        let limit = match destination {
+            RouteLocation::Stand(stand_id) => Some(ClearanceLimit::Stand(stand_id.clone())),
+            RouteLocation::HoldingPosition(id) => airport
+                .taxiway_holding_positions
+                .iter()
+                .find(|position| position.id == *id)
+                .map(|position| ClearanceLimit::HoldingPoint {
+                    taxiway_id: position.taxiway_id.clone(),
+                    runway: match known_target(airport, &inferred, position) {
+                        Some(HoldingPointTarget::Runway(runway_id)) => {
+                            Some(spoken_designator(airport, &runway_id, end, runways_in_use))
+                        },
+                        _ => None,
+                    },
+                }),
+            RouteLocation::RunwayExit(_) => limit_runway
+                .as_ref()
+                .map(|runway_id| ClearanceLimit::Runway(spoken_designator(airport, runway_id, end, runways_in_use))),
+            RouteLocation::Node(_) | RouteLocation::Point(_) => None,
+        };
+
+        let mut composer = Composer {
+            airport,
+            graph,
+            runways_in_use,
+            limit_node: match destination {
+                RouteLocation::HoldingPosition(_) => self.nodes.last().copied(),
+                _ => None,
+            },
+            limit_runway,
+            instructions: Vec::new(),
+            holding: Vec::new(),
+            inferred,
+        };
+
+        // Each node along with where the route goes after it, either the next node or the end of the route
+        let mut nodes = self
+            .nodes
+            .iter()
+            .copied()
+            .zip(
+                self.nodes[1.min(self.nodes.len())..]
+                    .iter()
+                    .map(|&node| graph.node(node).location)
+                    .chain([end]),
+            )
+            .peekable();
+
+        // A route which starts at a node rather than part way along an edge passes it before its first edge
+        if let Some((start, next)) =
+            nodes.next_if(|&(node, _)| self.geometry.0.first() == Some(&graph.node(node).location))
+        {
+            composer.visit_node(start, next);
+        }
+
+        for (index, &edge) in self.edges.iter().enumerate() {
+            if index > 0 {
+                let (previous, current) = (graph.edge(self.edges[index - 1]), graph.edge(edge));
+                let joins = |node: NodeId| {
+                    (node == previous.from || node == previous.to) && (node == current.from || node == current.to)
+                };
+
+                // Visit the nodes up to the one the edge is entered at
+                for (node, next) in nodes.by_ref() {
+                    composer.visit_node(node, next);
+
+                    if joins(node) {
+                        break;
+                    }
+                }
+            }
+
+            composer.travel_edge(edge);
+        }
+
+        for (node, next) in nodes {
+            composer.visit_node(node, next);
+        }
+
+        TaxiClearance {
+            limit,
+            instructions: composer.instructions,
+        }
+    }
+}
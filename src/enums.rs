@@ -1,7 +1,7 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Defines an enum and automatically assigns a number to each variant of an enum, and creates an Unknown variant for
-/// values outside of the range.
+/// values outside of the range. The enum is serialized back to the same number it was deserialized from.
 macro_rules! define_enum {
     ($name:ident { $($variant:ident),* }) => {
         #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -28,6 +28,20 @@ macro_rules! define_enum {
                 }
             }
         }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let index = match self {
+                    $name::Unknown(index) => *index,
+                    known => [$($name::$variant),*].iter().position(|variant| variant == known).unwrap_or_default() as i32,
+                };
+
+                serializer.serialize_i32(index)
+            }
+        }
     }
 }
 
@@ -0,0 +1,149 @@
+use std::{convert::Infallible, fmt};
+
+use crate::{
+    layer::Layer,
+    taxi_route::{Limitation, RouteLocation},
+};
+
+/// An error which prevented an airport from being parsed
+#[derive(Debug)]
+pub enum ParseError {
+    /// The document is not valid JSON, or is not laid out as a set of AMDB feature collections
+    Json(serde_json::Error),
+    /// A layer which must be present in the document is missing
+    MissingLayer(Layer),
+    /// A layer which must contain a feature has none
+    EmptyLayer(Layer),
+    /// A single feature of a layer could not be parsed
+    Feature(FeatureError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Json(e) => write!(f, "invalid AMDB document: {e}"),
+            ParseError::MissingLayer(layer) => write!(f, "layer {layer} is missing"),
+            ParseError::EmptyLayer(layer) => write!(f, "layer {layer} contains no features"),
+            ParseError::Feature(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Json(e) => Some(e),
+            ParseError::MissingLayer(_) | ParseError::EmptyLayer(_) => None,
+            ParseError::Feature(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(e: serde_json::Error) -> Self { ParseError::Json(e) }
+}
+
+impl From<FeatureError> for ParseError {
+    fn from(e: FeatureError) -> Self { ParseError::Feature(e) }
+}
+
+/// Describes a feature which could not be parsed, and where in the source document it was found
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureError {
+    pub layer: Layer,
+    /// The position of the feature within its layer's feature collection
+    pub index: usize,
+    /// The id of the feature, if it could be read
+    pub id: Option<u64>,
+    /// The path to the offending value within the feature, such as `properties.idrwy` or `geometry.coordinates[0]`
+    pub property: Option<String>,
+    pub reason: String,
+}
+
+impl fmt::Display for FeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} feature {}", self.layer, self.index)?;
+
+        if let Some(id) = self.id {
+            write!(f, " (id {id})")?;
+        }
+
+        match &self.property {
+            Some(property) => write!(f, ": {property}: {}", self.reason),
+            None => write!(f, ": {}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for FeatureError {}
+
+/// An error converting a single property of a feature into its output representation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyError {
+    /// The path to the property within the feature, such as `properties.idrwy`
+    pub property: &'static str,
+    pub reason: String,
+}
+
+impl PropertyError {
+    pub fn new(property: &'static str, reason: impl ToString) -> Self {
+        Self {
+            property,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}: {}", self.property, self.reason) }
+}
+
+impl std::error::Error for PropertyError {}
+
+impl From<Infallible> for PropertyError {
+    fn from(e: Infallible) -> Self { match e {} }
+}
+
+/// A runway identifier which is not made up of two threshold designators separated by a dot, such as `09L.27R`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRunwayId(pub String);
+
+impl fmt::Display for InvalidRunwayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "invalid runway identifier {:?}", self.0) }
+}
+
+impl std::error::Error for InvalidRunwayId {}
+
+/// A threshold designator which is neither a runway number with an optional side, such as `09L`, nor a name, such as
+/// `H1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRunwayDesignator(pub String);
+
+impl fmt::Display for InvalidRunwayDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "invalid runway designator {:?}", self.0) }
+}
+
+impl std::error::Error for InvalidRunwayDesignator {}
+
+/// An error which prevented a taxi route from being found
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteError {
+    /// A location of the route could not be found on the taxi network
+    UnknownLocation(RouteLocation),
+    /// No open path joins the two locations
+    NoRoute,
+    /// The locations are only joined by paths the aircraft is too large to follow, the first of which is limited here
+    Limited(Limitation),
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::UnknownLocation(location) => write!(f, "{location} is not on the taxi network"),
+            RouteError::NoRoute => f.write_str("no open route joins the locations"),
+            RouteError::Limited(limitation) => write!(f, "no route fits the aircraft, as {limitation}"),
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
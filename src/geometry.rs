@@ -0,0 +1,277 @@
+use geo::{BooleanOps, Coord, Line, LineString, MapCoords, MinimumRotatedRect, MultiLineString, MultiPolygon, Polygon};
+use rstar::{RTree, AABB};
+
+/// The mean radius of the earth in metres, as used by the haversine algorithms
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A local equirectangular projection between longitude and latitude and metres east and north of an origin. It is
+/// accurate over the extent of an airport.
+pub(crate) struct LocalProjection {
+    origin: Coord,
+    scale: f64,
+}
+
+impl LocalProjection {
+    pub fn new(origin: Coord) -> Self {
+        Self {
+            origin,
+            scale: origin.y.to_radians().cos(),
+        }
+    }
+
+    pub fn project(&self, coord: Coord) -> Coord {
+        Coord {
+            x: (coord.x - self.origin.x).to_radians() * EARTH_RADIUS * self.scale,
+            y: (coord.y - self.origin.y).to_radians() * EARTH_RADIUS,
+        }
+    }
+
+    pub fn unproject(&self, coord: Coord) -> Coord {
+        Coord {
+            x: self.origin.x + (coord.x / (EARTH_RADIUS * self.scale)).to_degrees(),
+            y: self.origin.y + (coord.y / EARTH_RADIUS).to_degrees(),
+        }
+    }
+}
+
+/// Dissolves a set of polygons into their union.
+///
+/// The boolean operations of geo fail on edges which nearly but not exactly coincide, as those shared by neighbouring
+/// elements often do when they are digitized separately. The polygons are snapped onto each other first, so that such
+/// edges coincide exactly.
+pub(crate) fn dissolve(polygons: &MultiPolygon) -> MultiPolygon {
+    snap_together(polygons)
+        .iter()
+        .fold(MultiPolygon::new(Vec::new()), |union, polygon| {
+            union.union(&MultiPolygon::new(vec![polygon.clone()]))
+        })
+}
+
+/// Snaps the vertices of a set of polygons within [JOIN_TOLERANCE] of each other onto a single vertex, and inserts a
+/// vertex wherever another polygon has one on an edge, so that edges shared between polygons run between the same
+/// vertices. Rings left with no area are dropped.
+fn snap_together(polygons: &MultiPolygon) -> MultiPolygon {
+    let within =
+        |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).abs() <= JOIN_TOLERANCE && (a[1] - b[1]).abs() <= JOIN_TOLERANCE;
+
+    // Each vertex is moved onto the first vertex seen within the tolerance of it
+    let mut vertices: RTree<[f64; 2]> = RTree::new();
+
+    let mut snap_ring = |ring: &LineString| -> Vec<Coord> {
+        ring.coords()
+            .map(|&coord| {
+                let point = [coord.x, coord.y];
+
+                match vertices
+                    .nearest_neighbor(&point)
+                    .filter(|&&vertex| within(vertex, point))
+                {
+                    Some(&vertex) => Coord::from(vertex),
+                    None => {
+                        vertices.insert(point);
+                        coord
+                    },
+                }
+            })
+            .collect()
+    };
+
+    let snapped: Vec<(Vec<Coord>, Vec<Vec<Coord>>)> = polygons
+        .iter()
+        .map(|polygon| {
+            (
+                snap_ring(polygon.exterior()),
+                polygon.interiors().iter().map(&mut snap_ring).collect(),
+            )
+        })
+        .collect();
+
+    // The vertices lying on each edge, other than its ends, are inserted along it in order
+    let split_ring = |ring: Vec<Coord>| -> Option<LineString> {
+        let mut coords: Vec<Coord> = Vec::with_capacity(ring.len());
+
+        for w in ring.windows(2) {
+            let segment = Line::new(w[0], w[1]);
+            let envelope = AABB::from_corners(
+                [
+                    segment.start.x.min(segment.end.x) - JOIN_TOLERANCE,
+                    segment.start.y.min(segment.end.y) - JOIN_TOLERANCE,
+                ],
+                [
+                    segment.start.x.max(segment.end.x) + JOIN_TOLERANCE,
+                    segment.start.y.max(segment.end.y) + JOIN_TOLERANCE,
+                ],
+            );
+
+            let mut on_edge: Vec<(f64, Coord)> = vertices
+                .locate_in_envelope_intersecting(&envelope)
+                .map(|&vertex| Coord::from(vertex))
+                .filter(|&vertex| vertex != segment.start && vertex != segment.end)
+                .filter_map(|vertex| {
+                    let position = fraction_along(segment, vertex);
+                    let closest = segment.start + segment.delta() * position;
+
+                    (position > 0.0 && position < 1.0 && within(closest.into(), vertex.into()))
+                        .then_some((position, vertex))
+                })
+                .collect();
+
+            on_edge.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            coords.push(segment.start);
+            coords.extend(on_edge.into_iter().map(|(_, vertex)| vertex));
+        }
+
+        coords.extend(ring.last());
+        coords.dedup();
+
+        (coords.len() >= 4).then(|| LineString::new(coords))
+    };
+
+    snapped
+        .into_iter()
+        .filter_map(|(exterior, interiors)| {
+            Some(Polygon::new(
+                split_ring(exterior)?,
+                interiors.into_iter().filter_map(split_ring).collect(),
+            ))
+        })
+        .collect()
+}
+
+/// How far the point closest to `point` lies along a segment, from 0 at its start to 1 at its end
+fn fraction_along(segment: Line, point: Coord) -> f64 {
+    let delta = segment.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+
+    match length_2 > 0.0 {
+        true => {
+            (((point.x - segment.start.x) * delta.x + (point.y - segment.start.y) * delta.y) / length_2).clamp(0.0, 1.0)
+        },
+        false => 0.0,
+    }
+}
+
+/// Gathers the polygons of a set of shapes into a single shape, without dissolving them into each other
+pub(crate) fn combine<'a>(shapes: impl IntoIterator<Item = &'a MultiPolygon>) -> MultiPolygon {
+    shapes.into_iter().flat_map(|shape| shape.iter().cloned()).collect()
+}
+
+/// The distance in degrees within which the ends of two lines are considered to meet, around a centimetre
+const JOIN_TOLERANCE: f64 = 1e-7;
+
+/// Joins lines which meet end to end into continuous chains, reversing lines where needed. Lines which branch off a
+/// chain start chains of their own.
+pub(crate) fn chain_lines<'a>(lines: impl IntoIterator<Item = &'a LineString>) -> MultiLineString {
+    let meets = |a: Coord, b: Coord| (a.x - b.x).abs() <= JOIN_TOLERANCE && (a.y - b.y).abs() <= JOIN_TOLERANCE;
+
+    let mut remaining: Vec<Vec<Coord>> = lines
+        .into_iter()
+        .filter(|line| line.0.len() > 1)
+        .map(|line| line.0.clone())
+        .collect();
+    let mut chains = Vec::new();
+
+    while !remaining.is_empty() {
+        let mut chain = remaining.remove(0);
+
+        loop {
+            let (first, last) = (chain[0], chain[chain.len() - 1]);
+            let Some(index) = remaining.iter().position(|line| {
+                let (start, end) = (line[0], line[line.len() - 1]);
+                meets(start, last) || meets(end, last) || meets(start, first) || meets(end, first)
+            }) else {
+                break;
+            };
+
+            let mut line = remaining.remove(index);
+
+            if meets(line[0], last) {
+                chain.extend(line.into_iter().skip(1));
+            } else if meets(line[line.len() - 1], last) {
+                chain.extend(line.into_iter().rev().skip(1));
+            } else {
+                if meets(line[0], first) {
+                    line.reverse();
+                }
+
+                line.pop();
+                line.append(&mut chain);
+                chain = line;
+            }
+        }
+
+        chains.push(LineString::new(chain));
+    }
+
+    MultiLineString::new(chains)
+}
+
+/// The long axis of the smallest rectangle enclosing a shape, running between the midpoints of its short sides
+pub(crate) struct Axis {
+    pub line: Line,
+    /// The length of the rectangle in metres
+    pub length: f64,
+    /// The width of the rectangle in metres
+    pub width: f64,
+}
+
+/// Finds the long axis of a shape, or None if it is empty
+pub(crate) fn long_axis(shape: &MultiPolygon) -> Option<Axis> {
+    let origin = *shape.iter().next()?.exterior().0.first()?;
+    let projection = LocalProjection::new(origin);
+    let rect = shape
+        .map_coords(|coord| projection.project(coord))
+        .minimum_rotated_rect()?;
+
+    let corners = &rect.exterior().0;
+    if corners.len() < 4 {
+        return None;
+    }
+
+    let side = |a: Coord, b: Coord| (b.x - a.x).hypot(b.y - a.y);
+    let midpoint = |a: Coord, b: Coord| (a + b) / 2.0;
+
+    let (start, end, length, width) = match (side(corners[0], corners[1]), side(corners[1], corners[2])) {
+        (first, second) if first >= second => (
+            midpoint(corners[3], corners[0]),
+            midpoint(corners[1], corners[2]),
+            first,
+            second,
+        ),
+        (first, second) => (
+            midpoint(corners[0], corners[1]),
+            midpoint(corners[2], corners[3]),
+            second,
+            first,
+        ),
+    };
+
+    Some(Axis {
+        line: Line::new(projection.unproject(start), projection.unproject(end)),
+        length,
+        width,
+    })
+}
+
+/// The radius in metres of the turn made at `corner` when travelling from `previous` to `next`, given in metres, or
+/// None if the path runs straight on.
+///
+/// The turn is taken as the circle which meets both segments tangentially halfway along the shorter of them, which
+/// follows a curve drawn as a chain of short segments and allows a sharp corner to be cut.
+pub(crate) fn turn_radius(previous: Coord, corner: Coord, next: Coord) -> Option<f64> {
+    let incoming = corner - previous;
+    let outgoing = next - corner;
+
+    let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+    let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+    let deflection = cross.atan2(dot).abs();
+
+    if deflection < 1e-6 {
+        return None;
+    }
+
+    let reach = incoming.x.hypot(incoming.y).min(outgoing.x.hypot(outgoing.y)) / 2.0;
+
+    Some(reach / (deflection / 2.0).tan())
+}
@@ -0,0 +1,146 @@
+use std::cell::OnceCell;
+
+use geo::{Centroid, Coord};
+
+use crate::{
+    layer::Layer,
+    output_types::{AnyFeature, HoldingPointTarget, RunwayId, TaxiwayHoldingPosition},
+    spatial_index::SpatialIndex,
+    taxi_graph::{EdgeKind, TaxiGraph},
+    Airport,
+};
+
+/// The distance in metres from a runway within which a holding position is taken to protect it, which comfortably
+/// covers the ICAO holding distances from the runway centerline
+const RUNWAY_HOLDING_DISTANCE: f64 = 150.0;
+
+/// The distance in metres along the taxi network searched for the runway or taxiway a holding position protects
+const NETWORK_SEARCH_DISTANCE: f64 = 500.0;
+
+/// The target of a holding position as found from its geometry, alongside the one supplied with the data
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredHoldingTarget {
+    pub holding_position_id: u64,
+    /// The runway or taxiway the holding position was found to protect, if any was found
+    pub inferred: Option<HoldingPointTarget>,
+    /// The target given by the holding position itself
+    pub supplied: Option<HoldingPointTarget>,
+}
+
+impl InferredHoldingTarget {
+    /// Whether the inferred target differs from the supplied one. A runway named from either end counts as the same
+    /// runway.
+    pub fn disagrees(&self) -> bool {
+        match (&self.inferred, &self.supplied) {
+            (Some(HoldingPointTarget::Runway(inferred)), Some(HoldingPointTarget::Runway(supplied))) => {
+                !inferred.same_runway(supplied)
+            },
+            (Some(inferred), Some(supplied)) => inferred != supplied,
+            _ => false,
+        }
+    }
+
+    /// The supplied target, or the inferred one where none was supplied
+    pub fn target(&self) -> Option<&HoldingPointTarget> { self.supplied.as_ref().or(self.inferred.as_ref()) }
+}
+
+/// The target of a holding position as supplied with the data, or the one inferred from its geometry where the data
+/// leaves it unknown. The targets of the airport are inferred into `inferred` the first time one is needed.
+pub(crate) fn known_target(
+    airport: &Airport, inferred: &OnceCell<Vec<InferredHoldingTarget>>, position: &TaxiwayHoldingPosition,
+) -> Option<HoldingPointTarget> {
+    position.holding_point_target.clone().or_else(|| {
+        inferred
+            .get_or_init(|| airport.infer_holding_targets())
+            .iter()
+            .find(|target| target.holding_position_id == position.id)
+            .and_then(|target| target.inferred.clone())
+    })
+}
+
+/// Infers the target of a holding position from the runways near it and the taxi network around it
+fn infer_target(
+    graph: &TaxiGraph, index: &SpatialIndex, position: &TaxiwayHoldingPosition,
+) -> Option<HoldingPointTarget> {
+    let starts: Vec<_> = graph.holding_position_nodes(position.id).collect();
+
+    let location = match starts.first() {
+        Some(&node) => graph.node(node).location,
+        None => position.geometry.centroid().map(Coord::from)?,
+    };
+
+    // The runways whose elements lie near the holding position, nearest first
+    let mut nearby: Vec<&RunwayId> = Vec::new();
+
+    for (feature, _) in index.within_distance(location, RUNWAY_HOLDING_DISTANCE, &[Layer::RunwayElement]) {
+        if let AnyFeature::RunwayElement(element) = feature {
+            if !nearby.iter().any(|runway_id| runway_id.same_runway(&element.runway_id)) {
+                nearby.push(&element.runway_id);
+            }
+        }
+    }
+
+    let reached = graph.nodes_within(&starts, NETWORK_SEARCH_DISTANCE);
+
+    if let Some(&nearest) = nearby.first() {
+        // Where several runways are near, the one the network leads to first is protected
+        let first_reached = reached.iter().find_map(|&(node, _)| {
+            graph
+                .edges_at(node)
+                .filter(|(_, edge)| edge.kind == EdgeKind::Runway)
+                .find_map(|(_, edge)| {
+                    edge.runway_id
+                        .as_ref()
+                        .filter(|runway_id| nearby.iter().any(|near| near.same_runway(runway_id)))
+                })
+        });
+
+        return Some(HoldingPointTarget::Runway(first_reached.unwrap_or(nearest).clone()));
+    }
+
+    // Away from runways, a holding position protects the nearest taxiway it leads onto
+    let mut own_taxiways: Vec<&String> = position.taxiway_id.iter().collect();
+
+    for &node in &starts {
+        for (_, edge) in graph.edges_at(node) {
+            if let Some(taxiway_id) = &edge.taxiway_id {
+                if !own_taxiways.contains(&taxiway_id) {
+                    own_taxiways.push(taxiway_id);
+                }
+            }
+        }
+    }
+
+    reached
+        .iter()
+        .filter(|(node, _)| !starts.contains(node))
+        .find_map(|&(node, _)| {
+            graph
+                .edges_at(node)
+                .filter(|(_, edge)| edge.kind == EdgeKind::Taxiway)
+                .find_map(|(_, edge)| edge.taxiway_id.as_ref().filter(|id| !own_taxiways.contains(id)))
+        })
+        .map(|taxiway_id| HoldingPointTarget::Taxiway(taxiway_id.clone()))
+}
+
+impl Airport {
+    /// Infers the runway or taxiway each holding position protects from its geometry, for checking or filling in the
+    /// targets supplied with the data.
+    ///
+    /// A holding position near a runway protects that runway, picking the one the taxi network leads to first where
+    /// several are near. Other holding positions protect the nearest taxiway along the network other than the one they
+    /// lie on.
+    pub fn infer_holding_targets(&self) -> Vec<InferredHoldingTarget> {
+        let graph = self.taxi_graph();
+        let index = self.spatial_index();
+
+        self.taxiway_holding_positions
+            .iter()
+            .map(|position| InferredHoldingTarget {
+                holding_position_id: position.id,
+                inferred: infer_target(&graph, &index, position),
+                supplied: position.holding_point_target.clone(),
+            })
+            .collect()
+    }
+}
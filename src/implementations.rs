@@ -1,83 +1,154 @@
-use geo::{Coord, LineString, Polygon};
+use geo::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
 
 use crate::{
     amdb::{
         geo_json::{self, Coordinate},
         layers,
     },
+    error::PropertyError,
     output_types::{
         AerodromeReferencePoint,
         ApronElement,
+        Blastpad,
+        ConstructionArea,
+        DeicingArea,
+        FinalApproachAndTakeoffArea,
+        FrequencyArea,
+        HoldingPointTarget,
+        Hotspot,
+        LandAndHoldShortOperationLocation,
         PaintedCenterline,
         ParkingStandArea,
         ParkingStandLocation,
         RunwayDisplacedArea,
         RunwayElement,
         RunwayExitLine,
+        RunwayId,
+        RunwayIntersection,
         RunwayMarking,
         RunwayShoulder,
         RunwayThreshold,
+        ServiceRoad,
         StandGuidanceLine,
+        Stopway,
         TaxiwayElement,
         TaxiwayGuidanceLine,
         TaxiwayHoldingPosition,
+        TaxiwayIntersectionMarking,
         TaxiwayShoulder,
+        TouchdownLiftoffArea,
+        VerticalLineStructure,
+        VerticalPointStructure,
+        VerticalPolygonalStructure,
+        Water,
     },
 };
 
-impl Into<Coord> for Coordinate {
-    fn into(self) -> Coord { Coord::from((self.lon, self.lat)) }
+impl From<Coordinate> for Coord {
+    fn from(coordinate: Coordinate) -> Self { Coord::from((coordinate.lon, coordinate.lat)) }
 }
 
-impl Into<LineString> for geo_json::Geometry<Vec<Coordinate>> {
-    fn into(self) -> LineString { LineString::from(self.coordinates.clone()) }
+impl From<geo_json::PointGeometry> for Coord {
+    fn from(geometry: geo_json::PointGeometry) -> Self {
+        match geometry {
+            geo_json::PointGeometry::Point(coordinate) => coordinate.into(),
+        }
+    }
+}
+
+impl From<geo_json::LineStringGeometry> for MultiLineString {
+    fn from(geometry: geo_json::LineStringGeometry) -> Self {
+        match geometry {
+            geo_json::LineStringGeometry::LineString(line) => MultiLineString::new(vec![line.into()]),
+            geo_json::LineStringGeometry::MultiLineString(lines) => lines.into_iter().collect(),
+        }
+    }
+}
+
+/// Builds a polygon from its rings, the first of which is the exterior and any further rings are holes
+fn polygon(rings: Vec<Vec<Coordinate>>) -> Polygon {
+    let mut rings = rings.into_iter().map(LineString::from);
+    let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+
+    Polygon::new(exterior, rings.collect())
 }
 
-impl Into<Polygon> for geo_json::Geometry<Vec<Vec<Coordinate>>> {
-    fn into(self) -> Polygon {
-        // TODO: Force to be clockwise
-        Polygon::new(LineString::from(self.coordinates[0].clone()), vec![])
+impl From<geo_json::PolygonGeometry> for MultiPolygon {
+    fn from(geometry: geo_json::PolygonGeometry) -> Self {
+        match geometry {
+            geo_json::PolygonGeometry::Polygon(rings) => MultiPolygon::new(vec![polygon(rings)]),
+            geo_json::PolygonGeometry::MultiPolygon(polygons) => polygons.into_iter().map(polygon).collect(),
+        }
     }
 }
 
 /// Replaces empty strings or $UNK strings with None
 fn normalize_string(s: Option<String>) -> Option<String> {
     match s {
-        Some(x) if x == String::from("") || x == String::from("$UNK") => None,
+        Some(x) if x.is_empty() || x == "$UNK" => None,
         x => x,
     }
 }
 
+/// Parses the `idrwy` property shared by the runway layers
+fn parse_runway_id(idrwy: String) -> Result<RunwayId, PropertyError> {
+    idrwy.try_into().map_err(|e| PropertyError::new("properties.idrwy", e))
+}
+
+/// Parses the `idp` property of a holding position, which names either a runway or a taxiway
+fn parse_holding_point_target(idp: Option<String>) -> Result<Option<HoldingPointTarget>, PropertyError> {
+    normalize_string(idp)
+        .map(HoldingPointTarget::try_from)
+        .transpose()
+        .map_err(|e| PropertyError::new("properties.idp", e))
+}
+
+/// Splits a dot separated list such as an aircraft type list into its entries
+fn split_list(s: Option<String>) -> Vec<String> {
+    normalize_string(s).map_or(vec![], |x| x.split('.').map(ToString::to_string).collect())
+}
+
 impl From<geo_json::Polygon<layers::ApronElement>> for ApronElement {
     fn from(apron: geo_json::Polygon<layers::ApronElement>) -> Self {
         Self {
             id: apron.properties.id,
             surface_type: apron.properties.gsurftyp,
             apron_id: normalize_string(apron.properties.idapron),
+            status: apron.properties.status,
+            vertex_elevations: apron.geometry.elevations(),
             geometry: apron.geometry.into(),
+            reoriented: apron.reoriented,
         }
     }
 }
 
-impl From<geo_json::Polygon<layers::RunwayMarking>> for RunwayMarking {
-    fn from(marking: geo_json::Polygon<layers::RunwayMarking>) -> Self {
-        Self {
+impl TryFrom<geo_json::Polygon<layers::RunwayMarking>> for RunwayMarking {
+    type Error = PropertyError;
+
+    fn try_from(marking: geo_json::Polygon<layers::RunwayMarking>) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: marking.properties.id,
-            runway_id: marking.properties.idrwy.into(),
+            runway_id: parse_runway_id(marking.properties.idrwy)?,
+            vertex_elevations: marking.geometry.elevations(),
             geometry: marking.geometry.into(),
-        }
+            reoriented: marking.reoriented,
+        })
     }
 }
 
-impl From<geo_json::Polygon<layers::RunwayShoulder>> for RunwayShoulder {
-    fn from(shoulder: geo_json::Polygon<layers::RunwayShoulder>) -> Self {
-        Self {
+impl TryFrom<geo_json::Polygon<layers::RunwayShoulder>> for RunwayShoulder {
+    type Error = PropertyError;
+
+    fn try_from(shoulder: geo_json::Polygon<layers::RunwayShoulder>) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: shoulder.properties.id,
-            runway_id: shoulder.properties.idrwy.into(),
+            runway_id: parse_runway_id(shoulder.properties.idrwy)?,
             status: shoulder.properties.status,
             surface_type: shoulder.properties.gsurftyp,
+            vertex_elevations: shoulder.geometry.elevations(),
             geometry: shoulder.geometry.into(),
-        }
+            reoriented: shoulder.reoriented,
+        })
     }
 }
 
@@ -88,21 +159,27 @@ impl From<geo_json::Polygon<layers::RunwayDisplacedArea>> for RunwayDisplacedAre
             threshold_id: area.properties.idthr,
             status: area.properties.status,
             surface_type: area.properties.surftype,
+            vertex_elevations: area.geometry.elevations(),
             geometry: area.geometry.into(),
+            reoriented: area.reoriented,
         }
     }
 }
 
-impl From<geo_json::Polygon<layers::RunwayElement>> for RunwayElement {
-    fn from(element: geo_json::Polygon<layers::RunwayElement>) -> Self {
-        Self {
+impl TryFrom<geo_json::Polygon<layers::RunwayElement>> for RunwayElement {
+    type Error = PropertyError;
+
+    fn try_from(element: geo_json::Polygon<layers::RunwayElement>) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: element.properties.id,
-            runway_id: element.properties.idrwy.into(),
+            runway_id: parse_runway_id(element.properties.idrwy)?,
             width: element.properties.width,
             length: element.properties.length,
             surface_type: element.properties.surftype,
+            vertex_elevations: element.geometry.elevations(),
             geometry: element.geometry.into(),
-        }
+            reoriented: element.reoriented,
+        })
     }
 }
 
@@ -117,8 +194,11 @@ impl From<geo_json::Polygon<layers::ParkingStandArea>> for ParkingStandArea {
             fuel: area.properties.fuel,
             towing: area.properties.towing,
             ground_power: area.properties.gndpower,
+            restricted_aircraft_types: split_list(area.properties.restacn),
             terminal_name: normalize_string(area.properties.termref),
+            vertex_elevations: area.geometry.elevations(),
             geometry: area.geometry.into(),
+            reoriented: area.reoriented,
         }
     }
 }
@@ -131,7 +211,9 @@ impl From<geo_json::Polygon<layers::TaxiwayElement>> for TaxiwayElement {
             apron_id: normalize_string(element.properties.idapron),
             surface_type: element.properties.gsurftyp,
             bridge: element.properties.bridge,
+            vertex_elevations: element.geometry.elevations(),
             geometry: element.geometry.into(),
+            reoriented: element.reoriented,
         }
     }
 }
@@ -141,7 +223,10 @@ impl From<geo_json::Polygon<layers::TaxiwayShoulder>> for TaxiwayShoulder {
         Self {
             id: shoulder.properties.id,
             surface_type: shoulder.properties.gsurftyp,
+            status: shoulder.properties.status,
+            vertex_elevations: shoulder.geometry.elevations(),
             geometry: shoulder.geometry.into(),
+            reoriented: shoulder.reoriented,
         }
     }
 }
@@ -153,8 +238,10 @@ impl From<geo_json::LineString<layers::StandGuidanceLine>> for StandGuidanceLine
             color: guidance_line.properties.color,
             direction: guidance_line.properties.direc,
             style: guidance_line.properties.style,
+            status: guidance_line.properties.status,
             stand_id: normalize_string(guidance_line.properties.idstd),
             terminal_name: normalize_string(guidance_line.properties.termref),
+            vertex_elevations: guidance_line.geometry.elevations(),
             geometry: guidance_line.geometry.into(),
         }
     }
@@ -169,6 +256,7 @@ impl From<geo_json::LineString<layers::TaxiwayGuidanceLine>> for TaxiwayGuidance
             style: guidance_line.properties.style,
             status: guidance_line.properties.status,
             taxiway_id: normalize_string(guidance_line.properties.idlin),
+            vertex_elevations: guidance_line.geometry.elevations(),
             geometry: guidance_line.geometry.into(),
         }
     }
@@ -183,31 +271,38 @@ impl From<geo_json::LineString<layers::RunwayExitLine>> for RunwayExitLine {
             style: exit_line.properties.style,
             status: exit_line.properties.status,
             taxiway_id: normalize_string(exit_line.properties.idlin),
+            vertex_elevations: exit_line.geometry.elevations(),
             geometry: exit_line.geometry.into(),
         }
     }
 }
 
-impl From<geo_json::LineString<layers::TaxiwayHoldingPosition>> for TaxiwayHoldingPosition {
-    fn from(position: geo_json::LineString<layers::TaxiwayHoldingPosition>) -> Self {
-        Self {
+impl TryFrom<geo_json::LineString<layers::TaxiwayHoldingPosition>> for TaxiwayHoldingPosition {
+    type Error = PropertyError;
+
+    fn try_from(position: geo_json::LineString<layers::TaxiwayHoldingPosition>) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: position.properties.id,
             status: position.properties.status,
             taxiway_id: normalize_string(position.properties.idlin),
             category: position.properties.catstop,
-            holding_point_target: normalize_string(position.properties.idp).map(Into::into),
+            holding_point_target: parse_holding_point_target(position.properties.idp)?,
+            vertex_elevations: position.geometry.elevations(),
             geometry: position.geometry.into(),
-        }
+        })
     }
 }
 
-impl From<geo_json::LineString<layers::PaintedCenterline>> for PaintedCenterline {
-    fn from(centerline: geo_json::LineString<layers::PaintedCenterline>) -> Self {
-        Self {
+impl TryFrom<geo_json::LineString<layers::PaintedCenterline>> for PaintedCenterline {
+    type Error = PropertyError;
+
+    fn try_from(centerline: geo_json::LineString<layers::PaintedCenterline>) -> Result<Self, Self::Error> {
+        Ok(Self {
             id: centerline.properties.id,
-            runway_id: centerline.properties.idrwy.into(),
+            runway_id: parse_runway_id(centerline.properties.idrwy)?,
+            vertex_elevations: centerline.geometry.elevations(),
             geometry: centerline.geometry.into(),
-        }
+        })
     }
 }
 
@@ -219,7 +314,8 @@ impl From<geo_json::Point<layers::AerodromeReferencePoint>> for AerodromeReferen
             iata_id: reference_point.properties.iata,
             airport_name: reference_point.properties.name,
             elevation: reference_point.properties.elev,
-            location: reference_point.geometry.coordinates.into(),
+            location_elevation: reference_point.geometry.elevation(),
+            location: reference_point.geometry.into(),
         }
     }
 }
@@ -228,12 +324,11 @@ impl From<geo_json::Point<layers::ParkingStandLocation>> for ParkingStandLocatio
     fn from(reference_point: geo_json::Point<layers::ParkingStandLocation>) -> Self {
         Self {
             id: reference_point.properties.id,
-            stand_id: reference_point.properties.idstd,
-            aircraft_types: reference_point
-                .properties
-                .acn
-                .map_or(vec![], |x| x.split(".").map(ToString::to_string).collect()),
-            location: reference_point.geometry.coordinates.into(),
+            stand_id: normalize_string(reference_point.properties.idstd),
+            aircraft_types: split_list(reference_point.properties.acn),
+            terminal_name: normalize_string(reference_point.properties.termref),
+            location_elevation: reference_point.geometry.elevation(),
+            location: reference_point.geometry.into(),
         }
     }
 }
@@ -248,12 +343,236 @@ impl From<geo_json::Point<layers::RunwayThreshold>> for RunwayThreshold {
             true_bearing: threshold.properties.brngtrue,
             magnetic_bearing: threshold.properties.brngmag,
             runway_slope: threshold.properties.rwyslope,
+            takeoff_run_available: threshold.properties.tora,
+            takeoff_distance_available: threshold.properties.toda,
+            accelerate_stop_distance_available: threshold.properties.asda,
+            landing_distance_available: threshold.properties.lda,
             category: threshold.properties.cat,
             papivasi: threshold.properties.vasis,
             status: threshold.properties.status,
             threshold_type: threshold.properties.thrtype,
 
-            location: threshold.geometry.coordinates.into(),
+            location_elevation: threshold.geometry.elevation(),
+            location: threshold.geometry.into(),
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::Blastpad>> for Blastpad {
+    fn from(blastpad: geo_json::Polygon<layers::Blastpad>) -> Self {
+        Self {
+            id: blastpad.properties.id,
+            threshold_id: blastpad.properties.idthr,
+            vertex_elevations: blastpad.geometry.elevations(),
+            geometry: blastpad.geometry.into(),
+            reoriented: blastpad.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::ConstructionArea>> for ConstructionArea {
+    fn from(area: geo_json::Polygon<layers::ConstructionArea>) -> Self {
+        Self {
+            id: area.properties.id,
+            planned_start_date: normalize_string(Some(area.properties.pstdate)),
+            planned_end_date: normalize_string(Some(area.properties.pendate)),
+            planned_operational_date: normalize_string(Some(area.properties.piocdate)),
+            vertex_elevations: area.geometry.elevations(),
+            geometry: area.geometry.into(),
+            reoriented: area.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::DeicingArea>> for DeicingArea {
+    fn from(area: geo_json::Polygon<layers::DeicingArea>) -> Self {
+        Self {
+            id: area.properties.id,
+            deicing_area_id: normalize_string(Some(area.properties.ident)),
+            base_id: normalize_string(area.properties.idbase),
+            surface_type: area.properties.gsurftyp,
+            status: area.properties.status,
+            restricted_aircraft_types: split_list(area.properties.restacn),
+            vertex_elevations: area.geometry.elevations(),
+            geometry: area.geometry.into(),
+            reoriented: area.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::FinalApproachAndTakeoffArea>> for FinalApproachAndTakeoffArea {
+    fn from(area: geo_json::Polygon<layers::FinalApproachAndTakeoffArea>) -> Self {
+        Self {
+            id: area.properties.id,
+            runway_id: normalize_string(area.properties.idrwy),
+            vertex_elevations: area.geometry.elevations(),
+            geometry: area.geometry.into(),
+            reoriented: area.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::FrequencyArea>> for FrequencyArea {
+    fn from(area: geo_json::Polygon<layers::FrequencyArea>) -> Self {
+        Self {
+            id: area.properties.id,
+            frequency: area.properties.frq,
+            station: normalize_string(area.properties.station),
+            vertex_elevations: area.geometry.elevations(),
+            geometry: area.geometry.into(),
+            reoriented: area.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::Hotspot>> for Hotspot {
+    fn from(hotspot: geo_json::Polygon<layers::Hotspot>) -> Self {
+        Self {
+            id: hotspot.properties.id,
+            hotspot_id: normalize_string(hotspot.properties.idhot),
+            vertex_elevations: hotspot.geometry.elevations(),
+            geometry: hotspot.geometry.into(),
+            reoriented: hotspot.reoriented,
+        }
+    }
+}
+
+impl TryFrom<geo_json::LineString<layers::LandAndHoldShortOperationLocation>> for LandAndHoldShortOperationLocation {
+    type Error = PropertyError;
+
+    fn try_from(
+        location: geo_json::LineString<layers::LandAndHoldShortOperationLocation>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: location.properties.id,
+            threshold_id: location.properties.idthr,
+            holding_point_target: parse_holding_point_target(Some(location.properties.idp))?,
+            vertex_elevations: location.geometry.elevations(),
+            geometry: location.geometry.into(),
+        })
+    }
+}
+
+impl From<geo_json::Polygon<layers::RunwayIntersection>> for RunwayIntersection {
+    fn from(intersection: geo_json::Polygon<layers::RunwayIntersection>) -> Self {
+        Self {
+            id: intersection.properties.id,
+            intersection_id: intersection.properties.idrwi,
+            surface_type: intersection.properties.surftype,
+            vertex_elevations: intersection.geometry.elevations(),
+            geometry: intersection.geometry.into(),
+            reoriented: intersection.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::ServiceRoad>> for ServiceRoad {
+    fn from(road: geo_json::Polygon<layers::ServiceRoad>) -> Self {
+        Self {
+            id: road.properties.id,
+            base_id: normalize_string(road.properties.idbase),
+            surface_type: road.properties.gsurftyp,
+            vertex_elevations: road.geometry.elevations(),
+            geometry: road.geometry.into(),
+            reoriented: road.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::Stopway>> for Stopway {
+    fn from(stopway: geo_json::Polygon<layers::Stopway>) -> Self {
+        Self {
+            id: stopway.properties.id,
+            threshold_id: stopway.properties.idthr,
+            status: stopway.properties.status,
+            surface_type: stopway.properties.surftype,
+            vertex_elevations: stopway.geometry.elevations(),
+            geometry: stopway.geometry.into(),
+            reoriented: stopway.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::LineString<layers::TaxiwayIntersectionMarking>> for TaxiwayIntersectionMarking {
+    fn from(marking: geo_json::LineString<layers::TaxiwayIntersectionMarking>) -> Self {
+        Self {
+            id: marking.properties.id,
+            taxiway_id: normalize_string(Some(marking.properties.idlin)),
+            vertex_elevations: marking.geometry.elevations(),
+            geometry: marking.geometry.into(),
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::TouchdownLiftoffArea>> for TouchdownLiftoffArea {
+    fn from(area: geo_json::Polygon<layers::TouchdownLiftoffArea>) -> Self {
+        Self {
+            id: area.properties.id,
+            runway_id: normalize_string(area.properties.idrwy),
+            surface_type: area.properties.surftype,
+            vertex_elevations: area.geometry.elevations(),
+            geometry: area.geometry.into(),
+            reoriented: area.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::LineString<layers::VerticalLineStructure>> for VerticalLineStructure {
+    fn from(structure: geo_json::LineString<layers::VerticalLineStructure>) -> Self {
+        Self {
+            id: structure.properties.id,
+            structure_type: structure.properties.linsttyp,
+            material: structure.properties.material,
+            height: structure.properties.height,
+            elevation: structure.properties.elev,
+            lighting: structure.properties.lighting,
+            marking: structure.properties.marking,
+            vertex_elevations: structure.geometry.elevations(),
+            geometry: structure.geometry.into(),
+        }
+    }
+}
+
+impl From<geo_json::Point<layers::VerticalPointStructure>> for VerticalPointStructure {
+    fn from(structure: geo_json::Point<layers::VerticalPointStructure>) -> Self {
+        Self {
+            id: structure.properties.id,
+            structure_type: structure.properties.pntsttyp,
+            material: structure.properties.material,
+            height: structure.properties.height,
+            elevation: structure.properties.elev,
+            radius: structure.properties.radius,
+            lighting: structure.properties.lighting,
+            marking: structure.properties.marking,
+            location_elevation: structure.geometry.elevation(),
+            location: structure.geometry.into(),
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::VerticalPolygonalStructure>> for VerticalPolygonalStructure {
+    fn from(structure: geo_json::Polygon<layers::VerticalPolygonalStructure>) -> Self {
+        Self {
+            id: structure.properties.id,
+            name: normalize_string(structure.properties.ident),
+            structure_type: structure.properties.plysttyp,
+            material: structure.properties.material,
+            height: structure.properties.height,
+            elevation: structure.properties.elev,
+            vertex_elevations: structure.geometry.elevations(),
+            geometry: structure.geometry.into(),
+            reoriented: structure.reoriented,
+        }
+    }
+}
+
+impl From<geo_json::Polygon<layers::Water>> for Water {
+    fn from(water: geo_json::Polygon<layers::Water>) -> Self {
+        Self {
+            id: water.properties.id,
+            vertex_elevations: water.geometry.elevations(),
+            geometry: water.geometry.into(),
+            reoriented: water.reoriented,
         }
     }
 }
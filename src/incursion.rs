@@ -0,0 +1,229 @@
+use std::cell::OnceCell;
+
+use geo::{
+    algorithm::line_intersection::line_intersection,
+    BoundingRect,
+    Coord,
+    Intersects,
+    Line,
+    LineIntersection,
+    LineString,
+    MultiPolygon,
+    Point,
+    Rect,
+};
+
+use crate::{
+    holding_position::known_target,
+    output_types::{HoldingPointTarget, RunwayId, TaxiwayHoldingPosition},
+    Airport,
+};
+
+/// A holding position a path passed before entering a runway
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassedHoldingPosition {
+    pub id: u64,
+    /// Whether the holding position protects the runway which was entered, named from either end. Holding positions
+    /// whose target is unknown in the data are judged by the target inferred from their geometry.
+    pub targets_runway: bool,
+}
+
+/// A stretch of a path which lies on a runway
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunwayCrossing {
+    pub runway_id: RunwayId,
+    /// Where the path enters the runway, or None if it starts on it
+    pub entry: Option<Coord>,
+    /// Where the path leaves the runway, or None if it ends on it
+    pub exit: Option<Coord>,
+    /// The holding position passed on the way onto the runway, since the path last left a runway. Where several were
+    /// passed, this is the last one protecting the runway entered, or the last one passed if none does.
+    pub holding_position: Option<PassedHoldingPosition>,
+}
+
+impl RunwayCrossing {
+    /// Whether the path entered the runway without passing a holding position which protects it
+    pub fn is_incursion(&self) -> bool {
+        self.entry.is_some()
+            && !self
+                .holding_position
+                .as_ref()
+                .is_some_and(|holding_position| holding_position.targets_runway)
+    }
+}
+
+/// The positions along a path where it crosses a set of lines, counted in segments from its start
+fn crossings(path: &[Line], lines: impl Iterator<Item = Line>) -> Vec<f64> {
+    let mut positions = Vec::new();
+
+    for line in lines {
+        let envelope = Rect::new(line.start, line.end);
+
+        for (index, segment) in path.iter().enumerate() {
+            if !envelope.intersects(&Rect::new(segment.start, segment.end)) {
+                continue;
+            }
+
+            let points = match line_intersection(*segment, line) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => vec![intersection],
+                Some(LineIntersection::Collinear { intersection }) => vec![intersection.start, intersection.end],
+                None => Vec::new(),
+            };
+
+            positions.extend(
+                points
+                    .into_iter()
+                    .map(|point| index as f64 + fraction_along(*segment, point)),
+            );
+        }
+    }
+
+    positions
+}
+
+/// How far a point on a segment lies along it, from 0 at its start to 1 at its end
+fn fraction_along(segment: Line, point: Coord) -> f64 {
+    let delta = segment.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+
+    match length_2 > 0.0 {
+        true => {
+            (((point.x - segment.start.x) * delta.x + (point.y - segment.start.y) * delta.y) / length_2).clamp(0.0, 1.0)
+        },
+        false => 0.0,
+    }
+}
+
+/// The point at a position along a path, counted in segments from its start
+fn point_at(path: &[Line], position: f64) -> Coord {
+    let index = (position.floor() as usize).min(path.len() - 1);
+    let segment = path[index];
+
+    segment.start + segment.delta() * (position - index as f64)
+}
+
+/// The stretches of a path which lie within a shape, as positions counted in segments from its start
+fn stretches_within(path: &[Line], shape: &MultiPolygon) -> Vec<(f64, f64)> {
+    let Some(bounds) = shape.bounding_rect() else {
+        return Vec::new();
+    };
+
+    if !path
+        .iter()
+        .any(|segment| bounds.intersects(&Rect::new(segment.start, segment.end)))
+    {
+        return Vec::new();
+    }
+
+    let boundary = shape
+        .iter()
+        .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .flat_map(|ring| ring.lines());
+
+    let mut positions = crossings(path, boundary);
+    positions.extend([0.0, path.len() as f64]);
+    positions.sort_by(f64::total_cmp);
+    positions.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+
+    let mut stretches: Vec<(f64, f64)> = Vec::new();
+
+    for pair in positions.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let middle = point_at(path, (start + end) / 2.0);
+
+        if !shape.intersects(&Point::from(middle)) {
+            continue;
+        }
+
+        match stretches.last_mut() {
+            Some((_, last_end)) if *last_end == start => *last_end = end,
+            _ => stretches.push((start, end)),
+        }
+    }
+
+    stretches
+}
+
+impl Airport {
+    /// Finds every runway a path enters or crosses, in the order they are entered.
+    ///
+    /// The path may be a planned route or a recorded track, given in longitude and latitude. Runways are taken as the
+    /// union of their runway elements, and a path which leaves and re-enters a runway crosses it once for each time it
+    /// enters.
+    pub fn runway_crossings(&self, path: &LineString) -> Vec<RunwayCrossing> {
+        let lines: Vec<_> = path.lines().collect();
+
+        if lines.is_empty() {
+            return Vec::new();
+        }
+
+        let end = lines.len() as f64;
+
+        let mut stretches: Vec<_> = self
+            .runways()
+            .iter()
+            .flat_map(|runway| {
+                stretches_within(&lines, &runway.surface(self))
+                    .into_iter()
+                    .map(move |stretch| (stretch, &runway.runway_id))
+            })
+            .collect();
+
+        stretches.sort_by(|((a, _), _), ((b, _), _)| a.total_cmp(b));
+
+        let mut holding_positions: Vec<_> = self
+            .taxiway_holding_positions
+            .iter()
+            .flat_map(|position| {
+                crossings(&lines, position.geometry.iter().flat_map(|line| line.lines()))
+                    .into_iter()
+                    .map(move |along| (along, position))
+            })
+            .collect();
+
+        holding_positions.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let inferred = OnceCell::new();
+
+        stretches
+            .iter()
+            .enumerate()
+            .map(|(index, &((start, finish), runway_id))| {
+                // Holding positions count from where the path last left a runway
+                let since = stretches[..index]
+                    .iter()
+                    .map(|((_, finish), _)| *finish)
+                    .filter(|&finish| finish <= start)
+                    .fold(0.0, f64::max);
+
+                let targets_runway = |position: &TaxiwayHoldingPosition| match known_target(self, &inferred, position) {
+                    Some(HoldingPointTarget::Runway(target)) => target.same_runway(runway_id),
+                    _ => false,
+                };
+
+                let passed: Vec<_> = holding_positions
+                    .iter()
+                    .filter(|(along, _)| *along >= since && *along <= start)
+                    .map(|(_, position)| position)
+                    .collect();
+
+                let holding_position = passed
+                    .iter()
+                    .rev()
+                    .find(|position| targets_runway(position))
+                    .or(passed.last())
+                    .map(|position| PassedHoldingPosition {
+                        id: position.id,
+                        targets_runway: targets_runway(position),
+                    });
+
+                RunwayCrossing {
+                    runway_id: runway_id.clone(),
+                    entry: (start > 0.0).then(|| point_at(&lines, start)),
+                    exit: (finish < end).then(|| point_at(&lines, finish)),
+                    holding_position,
+                }
+            })
+            .collect()
+    }
+}
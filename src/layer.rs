@@ -0,0 +1,66 @@
+/// Defines the Layer enum along with the name of the feature collection each layer is stored under in AMDB GeoJSON.
+macro_rules! define_layers {
+    ($($variant:ident => $name:literal),*) => {
+        /// A layer of an AMDB airport, such as the runway elements or the taxiway guidance lines
+        #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+        pub enum Layer {
+            $($variant),*
+        }
+
+        impl Layer {
+            /// The name of the feature collection this layer is stored under in AMDB GeoJSON
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Layer::$variant => $name),*
+                }
+            }
+
+            /// Looks up a layer by the name of its feature collection in AMDB GeoJSON
+            pub fn from_name(name: &str) -> Option<Layer> {
+                match name {
+                    $($name => Some(Layer::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+define_layers!(
+    AerodromeReferencePoint => "aerodromereferencepoint",
+    ApronElement => "apronelement",
+    Blastpad => "blastpad",
+    ConstructionArea => "constructionarea",
+    DeicingArea => "deicingarea",
+    FinalApproachAndTakeoffArea => "finalapproachandtakeoffarea",
+    FrequencyArea => "frequencyarea",
+    Hotspot => "hotspot",
+    LandAndHoldShortOperationLocation => "landandholdshortoperationlocation",
+    PaintedCenterline => "paintedcenterline",
+    ParkingStandArea => "parkingstandarea",
+    ParkingStandLocation => "parkingstandlocation",
+    RunwayDisplacedArea => "runwaydisplacedarea",
+    RunwayElement => "runwayelement",
+    RunwayExitLine => "runwayexitline",
+    RunwayIntersection => "runwayintersection",
+    RunwayMarking => "runwaymarking",
+    RunwayShoulder => "runwayshoulder",
+    RunwayThreshold => "runwaythreshold",
+    ServiceRoad => "serviceroad",
+    StandGuidanceLine => "standguidanceline",
+    Stopway => "stopway",
+    TaxiwayElement => "taxiwayelement",
+    TaxiwayGuidanceLine => "taxiwayguidanceline",
+    TaxiwayHoldingPosition => "taxiwayholdingposition",
+    TaxiwayIntersectionMarking => "taxiwayintersectionmarking",
+    TaxiwayShoulder => "taxiwayshoulder",
+    TouchdownLiftoffArea => "touchdownliftoffarea",
+    VerticalLineStructure => "verticallinestructure",
+    VerticalPointStructure => "verticalpointstructure",
+    VerticalPolygonalStructure => "verticalpolygonalstructure",
+    Water => "water"
+);
+
+impl std::fmt::Display for Layer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(self.name()) }
+}
@@ -1,52 +1,287 @@
-use crate::amdb::geo_json::AirportMapData;
+use std::{
+    collections::BTreeSet,
+    io::{BufReader, Read, Write},
+    iter,
+};
+
+use crate::{
+    error::{FeatureError, ParseError},
+    layer::Layer,
+    output_types::{AmdbElement, AnyFeature},
+    parser::Parser,
+    runway::Runway,
+    spatial_index::SpatialIndex,
+    stand::Stand,
+    taxi_graph::{TaxiGraph, DEFAULT_SNAP_TOLERANCE},
+    taxiway::Taxiway,
+};
 
 mod amdb;
+pub mod clearance;
 pub mod enums;
+pub mod error;
+mod geometry;
+pub mod holding_position;
 pub mod implementations;
+pub mod incursion;
+pub mod layer;
 pub mod output_types;
+mod parser;
+pub mod runway;
+pub mod runway_exit;
+pub mod spatial_index;
+pub mod stand;
+pub mod surface;
+pub mod taxi_graph;
+pub mod taxi_route;
+pub mod taxiway;
+mod writer;
 
+/// An airport, holding the features of each AMDB layer along with the runways, taxiways and stands built from them.
+///
+/// An airport can be assembled from its layers by filling in a default one and calling [Airport::rebuild_aggregates].
+/// The runways, taxiways and stands are not kept up to date with the layers, so the same call is needed after the
+/// layers are edited or filtered.
+#[derive(Default)]
 pub struct Airport {
     pub aerodrome_reference_point: output_types::AerodromeReferencePoint,
     pub apron_elements: Vec<output_types::ApronElement>,
+    pub blastpads: Vec<output_types::Blastpad>,
+    pub construction_areas: Vec<output_types::ConstructionArea>,
+    pub deicing_areas: Vec<output_types::DeicingArea>,
+    pub final_approach_and_takeoff_areas: Vec<output_types::FinalApproachAndTakeoffArea>,
+    pub frequency_areas: Vec<output_types::FrequencyArea>,
+    pub hotspots: Vec<output_types::Hotspot>,
+    pub land_and_hold_short_operation_locations: Vec<output_types::LandAndHoldShortOperationLocation>,
     pub painted_centerlines: Vec<output_types::PaintedCenterline>,
     pub parking_stand_areas: Vec<output_types::ParkingStandArea>,
     pub parking_stand_locations: Vec<output_types::ParkingStandLocation>,
     pub runway_displaced_areas: Vec<output_types::RunwayDisplacedArea>,
     pub runway_elements: Vec<output_types::RunwayElement>,
     pub runway_exit_lines: Vec<output_types::RunwayExitLine>,
+    pub runway_intersections: Vec<output_types::RunwayIntersection>,
     pub runway_markings: Vec<output_types::RunwayMarking>,
     pub runway_shoulders: Vec<output_types::RunwayShoulder>,
     pub runway_thresholds: Vec<output_types::RunwayThreshold>,
+    pub service_roads: Vec<output_types::ServiceRoad>,
     pub stand_guidance_lines: Vec<output_types::StandGuidanceLine>,
+    pub stopways: Vec<output_types::Stopway>,
     pub taxiway_elements: Vec<output_types::TaxiwayElement>,
     pub taxiway_guidance_lines: Vec<output_types::TaxiwayGuidanceLine>,
     pub taxiway_holding_positions: Vec<output_types::TaxiwayHoldingPosition>,
+    pub taxiway_intersection_markings: Vec<output_types::TaxiwayIntersectionMarking>,
     pub taxiway_shoulders: Vec<output_types::TaxiwayShoulder>,
+    pub touchdown_liftoff_areas: Vec<output_types::TouchdownLiftoffArea>,
+    pub vertical_line_structures: Vec<output_types::VerticalLineStructure>,
+    pub vertical_point_structures: Vec<output_types::VerticalPointStructure>,
+    pub vertical_polygonal_structures: Vec<output_types::VerticalPolygonalStructure>,
+    pub water: Vec<output_types::Water>,
+    /// The layers which were present in the source document. Layers which are absent are parsed as empty.
+    pub present_layers: BTreeSet<Layer>,
+    runways: Vec<Runway>,
+    stands: Vec<Stand>,
+    taxiways: Vec<Taxiway>,
+}
+
+impl Airport {
+    /// Whether the given layer was present in the source document, as opposed to being absent and left empty
+    pub fn has_layer(&self, layer: Layer) -> bool { self.present_layers.contains(&layer) }
+
+    /// Iterates over every feature of the airport, layer by layer
+    pub fn features(&self) -> impl Iterator<Item = AnyFeature<'_>> {
+        macro_rules! features {
+            ($($features:ident),*) => {
+                iter::once(AnyFeature::from(&self.aerodrome_reference_point))
+                    $(.chain(self.$features.iter().map(AnyFeature::from)))*
+            };
+        }
+
+        features!(
+            apron_elements,
+            blastpads,
+            construction_areas,
+            deicing_areas,
+            final_approach_and_takeoff_areas,
+            frequency_areas,
+            hotspots,
+            land_and_hold_short_operation_locations,
+            painted_centerlines,
+            parking_stand_areas,
+            parking_stand_locations,
+            runway_displaced_areas,
+            runway_elements,
+            runway_exit_lines,
+            runway_intersections,
+            runway_markings,
+            runway_shoulders,
+            runway_thresholds,
+            service_roads,
+            stand_guidance_lines,
+            stopways,
+            taxiway_elements,
+            taxiway_guidance_lines,
+            taxiway_holding_positions,
+            taxiway_intersection_markings,
+            taxiway_shoulders,
+            touchdown_liftoff_areas,
+            vertical_line_structures,
+            vertical_point_structures,
+            vertical_polygonal_structures,
+            water
+        )
+    }
+
+    /// Looks up a feature of any layer by its id
+    pub fn get(&self, id: u64) -> Option<AnyFeature<'_>> { self.features().find(|feature| feature.id() == id) }
+
+    /// Builds a spatial index over every feature of the airport. The index is not kept, so it should be built once and
+    /// reused for any number of queries.
+    pub fn spatial_index(&self) -> SpatialIndex<'_> { SpatialIndex::new(self) }
+
+    /// Builds the taxi network of the airport, joining line ends within [DEFAULT_SNAP_TOLERANCE] metres of each other.
+    /// The network is not kept, so it should be built once and reused for any number of routes.
+    pub fn taxi_graph(&self) -> TaxiGraph { TaxiGraph::new(self, DEFAULT_SNAP_TOLERANCE) }
+
+    /// Rebuilds the runways, taxiways and stands of the airport from its feature layers
+    pub fn rebuild_aggregates(&mut self) {
+        self.runways = runway::build_runways(self);
+        self.taxiways = taxiway::build_taxiways(self);
+        self.stands = stand::build_stands(self);
+    }
+
+    /// The runways of the airport, as built from its runway features when it was parsed or last rebuilt
+    pub fn runways(&self) -> &[Runway] { &self.runways }
+
+    /// Looks up a runway by the designator of either of its ends, such as `09L`, or by its runway id, such as `09L.27R`
+    pub fn runway(&self, designator: &str) -> Option<&Runway> {
+        if let Ok(runway_id) = output_types::RunwayId::try_from(designator.to_string()) {
+            return self
+                .runways
+                .iter()
+                .find(|runway| runway.runway_id.same_runway(&runway_id));
+        }
+
+        let designator = output_types::RunwayDesignator::try_from(designator).ok()?;
+
+        self.runways.iter().find(|runway| runway.has_end(&designator))
+    }
+
+    /// The runways which have problems with the features describing them
+    pub fn inconsistent_runways(&self) -> impl Iterator<Item = &Runway> {
+        self.runways.iter().filter(|runway| !runway.issues.is_empty())
+    }
+
+    /// The parking stands of the airport, as built from its stand features when it was parsed or last rebuilt
+    pub fn stands(&self) -> &[Stand] { &self.stands }
+
+    /// Looks up a parking stand by its stand id
+    pub fn stand(&self, stand_id: &str) -> Option<&Stand> {
+        self.stands.iter().find(|stand| stand.stand_id == stand_id)
+    }
+
+    /// The parking stands which have pieces missing or which disagree with each other
+    pub fn inconsistent_stands(&self) -> impl Iterator<Item = &Stand> {
+        self.stands.iter().filter(|stand| !stand.issues.is_empty())
+    }
+
+    /// The taxiways of the airport, as built from its taxiway features when it was parsed or last rebuilt
+    pub fn taxiways(&self) -> &[Taxiway] { &self.taxiways }
+
+    /// Looks up a taxiway by its designator, such as `B2`
+    pub fn taxiway(&self, designator: &str) -> Option<&Taxiway> {
+        self.taxiways.iter().find(|taxiway| taxiway.designator == designator)
+    }
 }
 
-fn map_vec<T, O: From<T>>(input: Vec<T>) -> Vec<O> { input.into_iter().map(Into::into).collect() }
-
-pub fn parse_airport(data: &str) -> Result<Airport, Box<dyn std::error::Error>> {
-    let mut airport: AirportMapData = serde_json::from_str(data)?;
-
-    let airport = Airport {
-        aerodrome_reference_point: airport.aerodrome_reference_point.features.remove(0).into(),
-        apron_elements: map_vec(airport.apron_element.features),
-        runway_elements: map_vec(airport.runway_element.features),
-        painted_centerlines: map_vec(airport.painted_centerline.features),
-        parking_stand_areas: map_vec(airport.parking_stand_area.features),
-        parking_stand_locations: map_vec(airport.parking_stand_location.features),
-        runway_displaced_areas: map_vec(airport.runway_displaced_area.features),
-        runway_exit_lines: map_vec(airport.runway_exit_line.features),
-        runway_markings: map_vec(airport.runway_marking.features),
-        runway_shoulders: map_vec(airport.runway_shoulder.features),
-        runway_thresholds: map_vec(airport.runway_threshold.features),
-        stand_guidance_lines: map_vec(airport.stand_guidance_line.features),
-        taxiway_elements: map_vec(airport.taxiway_element.features),
-        taxiway_guidance_lines: map_vec(airport.taxiway_guidance_line.features),
-        taxiway_holding_positions: map_vec(airport.taxiway_holding_position.features),
-        taxiway_shoulders: map_vec(airport.taxiway_shoulder.features),
-    };
+/// The direction in which the exterior ring of each polygon is wound. Holes are wound in the opposite direction.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Options controlling how an airport is parsed
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ParseOptions {
+    /// Skip features which cannot be parsed, returning an error for each of them, rather than failing
+    pub lenient: bool,
+    /// The winding every polygon is normalized to, or None to keep the winding of the source. Rings are closed if the
+    /// source left them open either way.
+    ///
+    /// Features with a ring which had to be reversed have their `reoriented` flag set.
+    pub winding: Option<Winding>,
+}
+
+/// Parses an airport from AMDB GeoJSON with the default options, failing if any feature cannot be parsed.
+///
+/// Layers other than the aerodrome reference point may be absent from the document, in which case they are left empty.
+pub fn parse_airport(data: &str) -> Result<Airport, ParseError> {
+    let (airport, _) = parse_airport_with_options(data, ParseOptions::default())?;
 
     Ok(airport)
 }
+
+/// Parses an airport from AMDB GeoJSON, skipping any features which cannot be parsed rather than failing.
+///
+/// Returns the airport along with an error for each feature which was skipped. Errors affecting the document as a
+/// whole, such as invalid JSON or a missing aerodrome reference point, still cause parsing to fail.
+pub fn parse_airport_lenient(data: &str) -> Result<(Airport, Vec<FeatureError>), ParseError> {
+    parse_airport_with_options(
+        data,
+        ParseOptions {
+            lenient: true,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parses an airport from AMDB GeoJSON with the given options, returning it along with an error for each feature which
+/// was skipped
+pub fn parse_airport_with_options(
+    data: &str, options: ParseOptions,
+) -> Result<(Airport, Vec<FeatureError>), ParseError> {
+    Parser::new(options).parse(serde_json::Deserializer::from_str(data))
+}
+
+/// Parses an airport from AMDB GeoJSON read from `reader`, as with [parse_airport].
+///
+/// Features are converted as they are read, so the document is never held in memory as a whole. The reader is
+/// buffered internally.
+pub fn parse_airport_from_reader<R: Read>(reader: R) -> Result<Airport, ParseError> {
+    let (airport, _) = parse_airport_from_reader_with_options(reader, ParseOptions::default())?;
+
+    Ok(airport)
+}
+
+/// Parses an airport from AMDB GeoJSON read from `reader`, skipping any features which cannot be parsed as with
+/// [parse_airport_lenient].
+pub fn parse_airport_lenient_from_reader<R: Read>(reader: R) -> Result<(Airport, Vec<FeatureError>), ParseError> {
+    parse_airport_from_reader_with_options(
+        reader,
+        ParseOptions {
+            lenient: true,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parses an airport from AMDB GeoJSON read from `reader` with the given options, as with
+/// [parse_airport_with_options]
+pub fn parse_airport_from_reader_with_options<R: Read>(
+    reader: R, options: ParseOptions,
+) -> Result<(Airport, Vec<FeatureError>), ParseError> {
+    Parser::new(options).parse(serde_json::Deserializer::from_reader(BufReader::new(reader)))
+}
+
+/// Serializes an airport back to AMDB GeoJSON, with each layer written as a FeatureCollection.
+///
+/// Values which were normalized to None when parsing, such as unknown identifiers, are written as `$UNK`. Layers which
+/// were present in the parsed document are written even if they are empty.
+pub fn serialize_airport(airport: &Airport) -> Result<String, serde_json::Error> { serde_json::to_string(airport) }
+
+/// Serializes an airport back to AMDB GeoJSON into `writer`, as with [serialize_airport].
+///
+/// Features are written as they are converted, so the document is never held in memory as a whole.
+pub fn serialize_airport_to_writer<W: Write>(airport: &Airport, writer: W) -> Result<(), serde_json::Error> {
+    serde_json::to_writer(writer, airport)
+}
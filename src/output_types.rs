@@ -1,28 +1,95 @@
-use geo::{Coord, LineString, Polygon};
-
-use crate::enums::{
-    Availability,
-    Bridge,
-    CatStop,
-    Direction,
-    GroundSurfaceType,
-    LandingCategory,
-    LineColour,
-    PapiVasi,
-    Status,
-    Style,
-    SurfaceType,
-    ThresholdType,
+use enum_dispatch::enum_dispatch;
+use geo::{
+    BoundingRect,
+    Coord,
+    Geometry,
+    HaversineBearing,
+    HaversineDistance,
+    MultiLineString,
+    MultiPolygon,
+    Point,
+    Rect,
 };
 
-trait AmdbElement {
+use crate::{
+    enums::{
+        Availability,
+        Bridge,
+        CatStop,
+        Conformance,
+        Direction,
+        GroundSurfaceType,
+        LandingCategory,
+        LineColour,
+        LineStructureType,
+        Material,
+        PapiVasi,
+        PointStructureType,
+        PolygonalStructureType,
+        Status,
+        Style,
+        SurfaceType,
+        ThresholdType,
+    },
+    error::{InvalidRunwayDesignator, InvalidRunwayId},
+    layer::Layer,
+};
+
+/// The elevation of each vertex of each part of a line geometry, in the same order as its coordinates
+pub type LineElevations = Vec<Vec<f64>>;
+
+/// The elevation of each vertex of each ring of each part of a polygon geometry, exterior ring first, in the same order
+/// as its coordinates
+pub type PolygonElevations = Vec<Vec<Vec<f64>>>;
+
+/// A feature of any layer of an airport
+#[enum_dispatch]
+pub trait AmdbElement {
+    /// The id of the feature, unique within its airport
     fn id(&self) -> u64;
+
+    /// The layer the feature belongs to
+    fn layer(&self) -> Layer;
+
+    /// The geometry of the feature
+    fn geometry(&self) -> Geometry;
+
+    /// The smallest rectangle enclosing the geometry of the feature, or None if it is empty
+    fn bounding_rect(&self) -> Option<Rect>;
+}
+
+impl<T: AmdbElement> AmdbElement for &T {
+    fn id(&self) -> u64 { T::id(self) }
+
+    fn layer(&self) -> Layer { T::layer(self) }
+
+    fn geometry(&self) -> Geometry { T::geometry(self) }
+
+    fn bounding_rect(&self) -> Option<Rect> { T::bounding_rect(self) }
 }
 
 macro_rules! implement {
-    ($type:ty) => {
+    ($type:ident, geometry) => {
+        implement!($type, {
+            fn geometry(&self) -> Geometry { self.geometry.clone().into() }
+
+            fn bounding_rect(&self) -> Option<Rect> { self.geometry.bounding_rect() }
+        });
+    };
+    ($type:ident, location) => {
+        implement!($type, {
+            fn geometry(&self) -> Geometry { Point::from(self.location).into() }
+
+            fn bounding_rect(&self) -> Option<Rect> { Some(Rect::new(self.location, self.location)) }
+        });
+    };
+    ($type:ident, { $($geometry:item)* }) => {
         impl AmdbElement for $type {
             fn id(&self) -> u64 { self.id }
+
+            fn layer(&self) -> Layer { Layer::$type }
+
+            $($geometry)*
         }
 
         impl std::hash::Hash for $type {
@@ -35,20 +102,213 @@ macro_rules! implement {
     };
 }
 
+/// The suffix of a runway designator, giving the side of a parallel runway or, in FAA data, the kind of aircraft the
+/// runway is for
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RunwaySide {
+    Left,
+    Center,
+    Right,
+    /// A water runway for seaplanes, suffixed `W`
+    Water,
+    /// A runway for gliders, suffixed `G`
+    Glider,
+    /// A runway for short take-off and landing aircraft, suffixed `S`
+    Stol,
+    /// A runway for ultralight aircraft, suffixed `U`
+    Ultralight,
+}
+
+impl RunwaySide {
+    /// The side of the same runway seen from its other end
+    pub fn reciprocal(self) -> Self {
+        match self {
+            RunwaySide::Left => RunwaySide::Right,
+            RunwaySide::Right => RunwaySide::Left,
+            side => side,
+        }
+    }
+}
+
+/// The designator of one end of a runway
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct RunwayId(pub String, pub String);
-
-impl From<String> for RunwayId {
-    fn from(s: String) -> Self {
-        let mut split = s.split('.');
-        let runway1_id = split.next().unwrap().to_string();
-        let runway2_id = split.next().unwrap().to_string();
-        RunwayId(runway1_id, runway2_id)
+pub enum RunwayDesignator {
+    /// A runway designated by its magnetic heading in tens of degrees from 1 to 36, such as `09L`
+    Numbered { number: u8, side: Option<RunwaySide> },
+    /// A runway or helipad designated by a name rather than its heading, such as `H1`
+    Named(String),
+}
+
+impl RunwayDesignator {
+    /// The approximate magnetic heading in degrees given by the runway number, such as 90 for `09L`
+    pub fn heading(&self) -> Option<f64> {
+        match self {
+            RunwayDesignator::Numbered { number, .. } => Some(f64::from(*number) * 10.0),
+            RunwayDesignator::Named(_) => None,
+        }
+    }
+
+    /// The designator of the other end of the runway, such as `27R` for `09L`. Named designators have no reciprocal.
+    pub fn reciprocal(&self) -> Option<Self> {
+        match self {
+            RunwayDesignator::Numbered { number, side } => Some(RunwayDesignator::Numbered {
+                number: (number + 17) % 36 + 1,
+                side: side.map(RunwaySide::reciprocal),
+            }),
+            RunwayDesignator::Named(_) => None,
+        }
+    }
+
+    /// The difference in degrees between the heading of the designator and a bearing, between 0 and 180
+    pub fn bearing_difference(&self, bearing: f64) -> Option<f64> {
+        let difference = (bearing - self.heading()?).rem_euclid(360.0);
+
+        Some(difference.min(360.0 - difference))
+    }
+
+    /// Whether a bearing, such as the magnetic bearing of a threshold, is within `tolerance` degrees of the heading of
+    /// the designator. Named designators never match.
+    pub fn matches_bearing(&self, bearing: f64, tolerance: f64) -> bool {
+        self.bearing_difference(bearing)
+            .is_some_and(|difference| difference <= tolerance)
+    }
+}
+
+impl TryFrom<&str> for RunwayDesignator {
+    type Error = InvalidRunwayDesignator;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let invalid = || InvalidRunwayDesignator(s.to_string());
+        let digits = s.chars().take_while(char::is_ascii_digit).count();
+
+        if digits == 0 {
+            return match s {
+                "" => Err(invalid()),
+                s if s.chars().all(|c| c.is_ascii_alphanumeric()) => Ok(RunwayDesignator::Named(s.to_string())),
+                _ => Err(invalid()),
+            };
+        }
+
+        let number = match s[..digits].parse::<u8>() {
+            Ok(number @ 1..=36) if digits <= 2 => number,
+            _ => return Err(invalid()),
+        };
+
+        let side = match &s[digits..] {
+            "" => None,
+            "L" => Some(RunwaySide::Left),
+            "C" => Some(RunwaySide::Center),
+            "R" => Some(RunwaySide::Right),
+            "W" => Some(RunwaySide::Water),
+            "G" => Some(RunwaySide::Glider),
+            "S" => Some(RunwaySide::Stol),
+            "U" => Some(RunwaySide::Ultralight),
+            _ => return Err(invalid()),
+        };
+
+        Ok(RunwayDesignator::Numbered { number, side })
+    }
+}
+
+impl TryFrom<String> for RunwayDesignator {
+    type Error = InvalidRunwayDesignator;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> { RunwayDesignator::try_from(s.as_str()) }
+}
+
+impl std::fmt::Display for RunwayDesignator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunwayDesignator::Numbered { number, side } => {
+                write!(f, "{number:02}")?;
+
+                match side {
+                    Some(RunwaySide::Left) => f.write_str("L"),
+                    Some(RunwaySide::Center) => f.write_str("C"),
+                    Some(RunwaySide::Right) => f.write_str("R"),
+                    Some(RunwaySide::Water) => f.write_str("W"),
+                    Some(RunwaySide::Glider) => f.write_str("G"),
+                    Some(RunwaySide::Stol) => f.write_str("S"),
+                    Some(RunwaySide::Ultralight) => f.write_str("U"),
+                    None => Ok(()),
+                }
+            },
+            RunwayDesignator::Named(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The identifier of a runway, made up of the designators of its two ends.
+///
+/// Runway ids are compared by their designators alone, so `9L.27R` equals `09L.27R`, but are displayed as they were
+/// given in the source data.
+#[derive(Debug, Clone)]
+pub struct RunwayId {
+    pub first: RunwayDesignator,
+    pub second: RunwayDesignator,
+    source: String,
+}
+
+impl RunwayId {
+    pub fn new(first: RunwayDesignator, second: RunwayDesignator) -> Self {
+        let source = format!("{first}.{second}");
+
+        Self { first, second, source }
+    }
+
+    /// Whether either end of the runway has the given designator
+    pub fn has_end(&self, designator: &RunwayDesignator) -> bool {
+        &self.first == designator || &self.second == designator
+    }
+
+    /// Whether another runway id names the same runway, from either end
+    pub fn same_runway(&self, other: &RunwayId) -> bool {
+        self == other || (&self.second, &self.first) == (&other.first, &other.second)
+    }
+
+    /// Whether the designators of the two ends are reciprocals of each other, as they should be. Named designators
+    /// have no reciprocal, so always count as matching.
+    pub fn is_reciprocal(&self) -> bool {
+        self.first
+            .reciprocal()
+            .is_none_or(|reciprocal| reciprocal == self.second)
+    }
+}
+
+impl PartialEq for RunwayId {
+    fn eq(&self, other: &Self) -> bool { (&self.first, &self.second) == (&other.first, &other.second) }
+}
+
+impl Eq for RunwayId {}
+
+impl std::hash::Hash for RunwayId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { (&self.first, &self.second).hash(state); }
+}
+
+impl TryFrom<String> for RunwayId {
+    type Error = InvalidRunwayId;
+
+    /// Parses a runway identifier such as `09L.27R`. Designators which are not reciprocals of each other are accepted,
+    /// and can be found with [RunwayId::is_reciprocal].
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let designators = match s.split('.').collect::<Vec<_>>()[..] {
+            [first, second] => RunwayDesignator::try_from(first).and_then(|first| Ok((first, second.try_into()?))),
+            _ => return Err(InvalidRunwayId(s)),
+        };
+
+        match designators {
+            Ok((first, second)) => Ok(RunwayId {
+                first,
+                second,
+                source: s,
+            }),
+            Err(_) => Err(InvalidRunwayId(s)),
+        }
     }
 }
 
-impl ToString for RunwayId {
-    fn to_string(&self) -> String { format!("{}.{}", self.0, self.1) }
+impl std::fmt::Display for RunwayId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str(&self.source) }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -57,12 +317,24 @@ pub enum HoldingPointTarget {
     Runway(RunwayId),
 }
 
-impl From<String> for HoldingPointTarget {
-    fn from(s: String) -> Self {
-        if s.contains(".") {
-            HoldingPointTarget::Runway(s.into())
-        } else {
-            HoldingPointTarget::Taxiway(s)
+impl TryFrom<String> for HoldingPointTarget {
+    type Error = InvalidRunwayId;
+
+    /// Parses the target of a holding position. Targets containing a dot name a runway, and anything else names a
+    /// taxiway.
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        match s.contains('.') {
+            true => RunwayId::try_from(s).map(HoldingPointTarget::Runway),
+            false => Ok(HoldingPointTarget::Taxiway(s)),
+        }
+    }
+}
+
+impl std::fmt::Display for HoldingPointTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HoldingPointTarget::Taxiway(taxiway) => f.write_str(taxiway),
+            HoldingPointTarget::Runway(runway_id) => runway_id.fmt(f),
         }
     }
 }
@@ -72,10 +344,13 @@ pub struct ApronElement {
     pub id: u64,
     pub surface_type: GroundSurfaceType,
     pub apron_id: Option<String>,
-    pub geometry: Polygon,
+    pub status: Status,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(ApronElement);
+implement!(ApronElement, geometry);
 
 #[derive(Debug, Clone)]
 pub struct RunwayElement {
@@ -84,19 +359,23 @@ pub struct RunwayElement {
     pub width: f64,
     pub length: f64,
     pub surface_type: SurfaceType,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(RunwayElement);
+implement!(RunwayElement, geometry);
 
 #[derive(Debug, Clone)]
 pub struct RunwayMarking {
     pub id: u64,
     pub runway_id: RunwayId,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(RunwayMarking);
+implement!(RunwayMarking, geometry);
 
 #[derive(Debug, Clone)]
 pub struct RunwayShoulder {
@@ -104,10 +383,12 @@ pub struct RunwayShoulder {
     pub runway_id: RunwayId,
     pub status: Status,
     pub surface_type: GroundSurfaceType,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(RunwayShoulder);
+implement!(RunwayShoulder, geometry);
 
 #[derive(Debug, Clone)]
 pub struct RunwayDisplacedArea {
@@ -115,10 +396,12 @@ pub struct RunwayDisplacedArea {
     pub threshold_id: String,
     pub status: Status,
     pub surface_type: SurfaceType,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(RunwayDisplacedArea);
+implement!(RunwayDisplacedArea, geometry);
 
 #[derive(Debug, Clone)]
 pub struct ParkingStandArea {
@@ -130,11 +413,14 @@ pub struct ParkingStandArea {
     pub fuel: String,
     pub towing: Availability,
     pub ground_power: Availability,
+    pub restricted_aircraft_types: Vec<String>,
     pub terminal_name: Option<String>,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(ParkingStandArea);
+implement!(ParkingStandArea, geometry);
 
 #[derive(Debug, Clone)]
 pub struct TaxiwayElement {
@@ -143,19 +429,24 @@ pub struct TaxiwayElement {
     pub apron_id: Option<String>,
     pub surface_type: GroundSurfaceType,
     pub bridge: Bridge,
-    pub geometry: Polygon,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(TaxiwayElement);
+implement!(TaxiwayElement, geometry);
 
 #[derive(Debug, Clone)]
 pub struct TaxiwayShoulder {
     pub id: u64,
     pub surface_type: GroundSurfaceType,
-    pub geometry: Polygon,
+    pub status: Status,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
 }
 
-implement!(TaxiwayShoulder);
+implement!(TaxiwayShoulder, geometry);
 
 #[derive(Debug, Clone)]
 pub struct StandGuidanceLine {
@@ -163,12 +454,14 @@ pub struct StandGuidanceLine {
     pub color: LineColour,
     pub direction: Direction,
     pub style: Style,
+    pub status: Status,
     pub stand_id: Option<String>,
     pub terminal_name: Option<String>,
-    pub geometry: LineString,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
 }
 
-implement!(StandGuidanceLine);
+implement!(StandGuidanceLine, geometry);
 
 #[derive(Debug, Clone)]
 pub struct TaxiwayGuidanceLine {
@@ -178,10 +471,11 @@ pub struct TaxiwayGuidanceLine {
     pub style: Style,
     pub status: Status,
     pub taxiway_id: Option<String>,
-    pub geometry: LineString,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
 }
 
-implement!(TaxiwayGuidanceLine);
+implement!(TaxiwayGuidanceLine, geometry);
 
 #[derive(Debug, Clone)]
 pub struct RunwayExitLine {
@@ -191,10 +485,11 @@ pub struct RunwayExitLine {
     pub style: Style,
     pub status: Status,
     pub taxiway_id: Option<String>,
-    pub geometry: LineString,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
 }
 
-implement!(RunwayExitLine);
+implement!(RunwayExitLine, geometry);
 
 #[derive(Debug, Clone)]
 pub struct TaxiwayHoldingPosition {
@@ -203,12 +498,13 @@ pub struct TaxiwayHoldingPosition {
     pub taxiway_id: Option<String>,
     pub category: CatStop,
     pub holding_point_target: Option<HoldingPointTarget>,
-    pub geometry: LineString,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
 }
 
-implement!(TaxiwayHoldingPosition);
+implement!(TaxiwayHoldingPosition, geometry);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct AerodromeReferencePoint {
     pub id: u64,
     pub airport_id: String,
@@ -216,9 +512,10 @@ pub struct AerodromeReferencePoint {
     pub airport_name: String,
     pub elevation: f64,
     pub location: Coord,
+    pub location_elevation: Option<f64>,
 }
 
-implement!(AerodromeReferencePoint);
+implement!(AerodromeReferencePoint, location);
 
 #[derive(Debug, Clone)]
 pub struct RunwayThreshold {
@@ -229,30 +526,321 @@ pub struct RunwayThreshold {
     pub true_bearing: f64,
     pub magnetic_bearing: f64,
     pub runway_slope: f64,
+    pub takeoff_run_available: f64,
+    pub takeoff_distance_available: f64,
+    pub accelerate_stop_distance_available: f64,
+    pub landing_distance_available: f64,
     pub category: LandingCategory,
     pub papivasi: PapiVasi,
     pub status: Status,
     pub threshold_type: ThresholdType,
     pub location: Coord,
+    pub location_elevation: Option<f64>,
+}
+
+implement!(RunwayThreshold, location);
+
+impl RunwayThreshold {
+    /// Parses the designator of the threshold, such as `09L`
+    pub fn designator(&self) -> Result<RunwayDesignator, InvalidRunwayDesignator> {
+        RunwayDesignator::try_from(self.threshold_id.as_str())
+    }
+
+    /// Whether the magnetic bearing of the threshold is within `tolerance` degrees of the heading given by its
+    /// designator. Thresholds with a named or invalid designator never match.
+    pub fn matches_designator(&self, tolerance: f64) -> bool {
+        self.designator()
+            .is_ok_and(|designator| designator.matches_bearing(self.magnetic_bearing, tolerance))
+    }
+
+    /// The distance in metres from the threshold to `point`, measured along the runway centerline in the direction of
+    /// the threshold's true bearing. Negative if the point lies behind the threshold.
+    pub fn distance_along_runway(&self, point: Coord) -> f64 {
+        let threshold = Point::from(self.location);
+        let point = Point::from(point);
+        let angle = (threshold.haversine_bearing(point) - self.true_bearing).to_radians();
+
+        threshold.haversine_distance(&point) * angle.cos()
+    }
+
+    /// The takeoff run available in metres when beginning the takeoff roll from `point`, such as an intersection
+    /// departure.
+    ///
+    /// The takeoff run starts at `runway_end`, the end of the runway behind the threshold, which lies before the
+    /// threshold where it is displaced. Points behind the runway end get the full distance. See
+    /// [Runway::takeoff_run_available_from](crate::runway::Runway::takeoff_run_available_from) to find the runway end.
+    pub fn takeoff_run_available_from(&self, runway_end: Coord, point: Coord) -> f64 {
+        let distance = self.distance_along_runway(point) - self.distance_along_runway(runway_end);
+
+        remaining_distance(self.takeoff_run_available, distance)
+    }
+
+    /// The landing distance available in metres when touching down at `point`, such as the end of a displaced area.
+    ///
+    /// The landing distance starts at the threshold location, so points behind it get the full distance.
+    pub fn landing_distance_available_from(&self, point: Coord) -> f64 {
+        remaining_distance(self.landing_distance_available, self.distance_along_runway(point))
+    }
 }
 
-implement!(RunwayThreshold);
+/// The part of a declared distance left after travelling `distance` along it
+fn remaining_distance(declared_distance: f64, distance: f64) -> f64 { (declared_distance - distance.max(0.0)).max(0.0) }
 
 #[derive(Debug, Clone)]
 pub struct ParkingStandLocation {
     pub id: u64,
     pub stand_id: Option<String>,
     pub aircraft_types: Vec<String>,
+    pub terminal_name: Option<String>,
     pub location: Coord,
+    pub location_elevation: Option<f64>,
 }
 
-implement!(ParkingStandLocation);
+implement!(ParkingStandLocation, location);
 
 #[derive(Debug, Clone)]
 pub struct PaintedCenterline {
     pub id: u64,
     pub runway_id: RunwayId,
-    pub geometry: LineString,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
+}
+
+implement!(PaintedCenterline, geometry);
+
+#[derive(Debug, Clone)]
+pub struct Blastpad {
+    pub id: u64,
+    pub threshold_id: String,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(Blastpad, geometry);
+
+#[derive(Debug, Clone)]
+pub struct ConstructionArea {
+    pub id: u64,
+    pub planned_start_date: Option<String>,
+    pub planned_end_date: Option<String>,
+    pub planned_operational_date: Option<String>,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(ConstructionArea, geometry);
+
+#[derive(Debug, Clone)]
+pub struct DeicingArea {
+    pub id: u64,
+    pub deicing_area_id: Option<String>,
+    pub base_id: Option<String>,
+    pub surface_type: GroundSurfaceType,
+    pub status: Status,
+    pub restricted_aircraft_types: Vec<String>,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(DeicingArea, geometry);
+
+#[derive(Debug, Clone)]
+pub struct FinalApproachAndTakeoffArea {
+    pub id: u64,
+    pub runway_id: Option<String>,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(FinalApproachAndTakeoffArea, geometry);
+
+#[derive(Debug, Clone)]
+pub struct FrequencyArea {
+    pub id: u64,
+    pub frequency: f64,
+    pub station: Option<String>,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(FrequencyArea, geometry);
+
+#[derive(Debug, Clone)]
+pub struct Hotspot {
+    pub id: u64,
+    pub hotspot_id: Option<String>,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(Hotspot, geometry);
+
+#[derive(Debug, Clone)]
+pub struct LandAndHoldShortOperationLocation {
+    pub id: u64,
+    pub threshold_id: String,
+    pub holding_point_target: Option<HoldingPointTarget>,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
+}
+
+implement!(LandAndHoldShortOperationLocation, geometry);
+
+#[derive(Debug, Clone)]
+pub struct RunwayIntersection {
+    pub id: u64,
+    pub intersection_id: String,
+    pub surface_type: SurfaceType,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(RunwayIntersection, geometry);
+
+#[derive(Debug, Clone)]
+pub struct ServiceRoad {
+    pub id: u64,
+    pub base_id: Option<String>,
+    pub surface_type: GroundSurfaceType,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(ServiceRoad, geometry);
+
+#[derive(Debug, Clone)]
+pub struct Stopway {
+    pub id: u64,
+    pub threshold_id: String,
+    pub status: Status,
+    pub surface_type: SurfaceType,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(Stopway, geometry);
+
+#[derive(Debug, Clone)]
+pub struct TaxiwayIntersectionMarking {
+    pub id: u64,
+    pub taxiway_id: Option<String>,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
+}
+
+implement!(TaxiwayIntersectionMarking, geometry);
+
+#[derive(Debug, Clone)]
+pub struct TouchdownLiftoffArea {
+    pub id: u64,
+    pub runway_id: Option<String>,
+    pub surface_type: SurfaceType,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(TouchdownLiftoffArea, geometry);
+
+#[derive(Debug, Clone)]
+pub struct VerticalLineStructure {
+    pub id: u64,
+    pub structure_type: LineStructureType,
+    pub material: Material,
+    pub height: f64,
+    pub elevation: f64,
+    pub lighting: Conformance,
+    pub marking: Conformance,
+    pub geometry: MultiLineString,
+    pub vertex_elevations: Option<LineElevations>,
+}
+
+implement!(VerticalLineStructure, geometry);
+
+#[derive(Debug, Clone)]
+pub struct VerticalPointStructure {
+    pub id: u64,
+    pub structure_type: PointStructureType,
+    pub material: Material,
+    pub height: f64,
+    pub elevation: f64,
+    pub radius: f64,
+    pub lighting: Conformance,
+    pub marking: Conformance,
+    pub location: Coord,
+    pub location_elevation: Option<f64>,
 }
 
-implement!(PaintedCenterline);
+implement!(VerticalPointStructure, location);
+
+#[derive(Debug, Clone)]
+pub struct VerticalPolygonalStructure {
+    pub id: u64,
+    pub name: Option<String>,
+    pub structure_type: PolygonalStructureType,
+    pub material: Material,
+    pub height: f64,
+    pub elevation: f64,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(VerticalPolygonalStructure, geometry);
+
+#[derive(Debug, Clone)]
+pub struct Water {
+    pub id: u64,
+    pub geometry: MultiPolygon,
+    pub vertex_elevations: Option<PolygonElevations>,
+    pub reoriented: bool,
+}
+
+implement!(Water, geometry);
+
+/// A reference to a feature of any layer of an airport
+#[enum_dispatch(AmdbElement)]
+#[derive(Debug, Clone, Copy)]
+pub enum AnyFeature<'a> {
+    AerodromeReferencePoint(&'a AerodromeReferencePoint),
+    ApronElement(&'a ApronElement),
+    Blastpad(&'a Blastpad),
+    ConstructionArea(&'a ConstructionArea),
+    DeicingArea(&'a DeicingArea),
+    FinalApproachAndTakeoffArea(&'a FinalApproachAndTakeoffArea),
+    FrequencyArea(&'a FrequencyArea),
+    Hotspot(&'a Hotspot),
+    LandAndHoldShortOperationLocation(&'a LandAndHoldShortOperationLocation),
+    PaintedCenterline(&'a PaintedCenterline),
+    ParkingStandArea(&'a ParkingStandArea),
+    ParkingStandLocation(&'a ParkingStandLocation),
+    RunwayDisplacedArea(&'a RunwayDisplacedArea),
+    RunwayElement(&'a RunwayElement),
+    RunwayExitLine(&'a RunwayExitLine),
+    RunwayIntersection(&'a RunwayIntersection),
+    RunwayMarking(&'a RunwayMarking),
+    RunwayShoulder(&'a RunwayShoulder),
+    RunwayThreshold(&'a RunwayThreshold),
+    ServiceRoad(&'a ServiceRoad),
+    StandGuidanceLine(&'a StandGuidanceLine),
+    Stopway(&'a Stopway),
+    TaxiwayElement(&'a TaxiwayElement),
+    TaxiwayGuidanceLine(&'a TaxiwayGuidanceLine),
+    TaxiwayHoldingPosition(&'a TaxiwayHoldingPosition),
+    TaxiwayIntersectionMarking(&'a TaxiwayIntersectionMarking),
+    TaxiwayShoulder(&'a TaxiwayShoulder),
+    TouchdownLiftoffArea(&'a TouchdownLiftoffArea),
+    VerticalLineStructure(&'a VerticalLineStructure),
+    VerticalPointStructure(&'a VerticalPointStructure),
+    VerticalPolygonalStructure(&'a VerticalPolygonalStructure),
+    Water(&'a Water),
+}
@@ -0,0 +1,370 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+use crate::{
+    amdb::{
+        geo_json::{self, Feature},
+        layers,
+    },
+    error::{FeatureError, ParseError, PropertyError},
+    layer::Layer,
+    output_types::AerodromeReferencePoint,
+    Airport,
+    ParseOptions,
+};
+
+/// Parses a single raw feature of a layer into its output type
+fn parse_feature<T, O>(
+    layer: Layer, index: usize, feature: serde_json::Value, options: &ParseOptions,
+) -> Result<O, FeatureError>
+where
+    T: DeserializeOwned + Feature,
+    O: TryFrom<T>,
+    PropertyError: From<O::Error>,
+{
+    let id = feature.pointer("/properties/id").and_then(serde_json::Value::as_u64);
+    let error = |property, reason| FeatureError {
+        layer,
+        index,
+        id,
+        property,
+        reason,
+    };
+
+    let mut feature: T = serde_path_to_error::deserialize(feature).map_err(|e| {
+        let property = e.path().to_string();
+        error((property != ".").then_some(property), e.into_inner().to_string())
+    })?;
+
+    feature.normalize(options);
+
+    O::try_from(feature).map_err(|e| {
+        let e = PropertyError::from(e);
+        error(Some(e.property.to_string()), e.reason)
+    })
+}
+
+/// Tracks the features which failed to parse.
+///
+/// Serde visitors can only fail with the deserializer's own error type, so a feature error which should abort parsing
+/// is stashed in `error` and recovered once the deserializer has unwound.
+struct Diagnostics {
+    options: ParseOptions,
+    warnings: Vec<FeatureError>,
+    error: Option<FeatureError>,
+}
+
+/// Parses an airport one feature at a time, either failing on the first feature which cannot be parsed or, when
+/// lenient, skipping it and recording why in `warnings`.
+///
+/// Only a single raw feature is held in memory at any time, each one being converted into its output type as soon as
+/// it has been read.
+pub(crate) struct Parser {
+    diagnostics: Diagnostics,
+    airport: Airport,
+    aerodrome_reference_points: Vec<AerodromeReferencePoint>,
+}
+
+impl Parser {
+    pub fn new(options: ParseOptions) -> Self {
+        Self {
+            diagnostics: Diagnostics {
+                options,
+                warnings: Vec::new(),
+                error: None,
+            },
+            airport: Airport::default(),
+            aerodrome_reference_points: Vec::new(),
+        }
+    }
+
+    pub fn parse<'de, R>(
+        mut self, mut deserializer: serde_json::Deserializer<R>,
+    ) -> Result<(Airport, Vec<FeatureError>), ParseError>
+    where
+        R: serde_json::de::Read<'de>,
+    {
+        if let Err(e) = deserializer.deserialize_map(AirportVisitor { parser: &mut self }) {
+            return Err(match self.diagnostics.error.take() {
+                Some(feature_error) => feature_error.into(),
+                None => e.into(),
+            });
+        }
+
+        deserializer.end()?;
+
+        if !self.airport.has_layer(Layer::AerodromeReferencePoint) {
+            return Err(ParseError::MissingLayer(Layer::AerodromeReferencePoint));
+        }
+
+        self.airport.aerodrome_reference_point = match self.aerodrome_reference_points.into_iter().next() {
+            Some(reference_point) => reference_point,
+            None => return Err(ParseError::EmptyLayer(Layer::AerodromeReferencePoint)),
+        };
+
+        self.airport.rebuild_aggregates();
+
+        Ok((self.airport, self.diagnostics.warnings))
+    }
+}
+
+/// Deserializes the next value of the map as the feature collection of a layer, given the feature type the layer is
+/// stored as and the vector its features are collected into
+macro_rules! next_layer {
+    ($map:ident, $parser:ident, $layer:ident : $feature:ident => $features:expr) => {
+        $map.next_value_seed(LayerSeed::<geo_json::$feature<layers::$layer>, _> {
+            layer: Layer::$layer,
+            features: &mut $features,
+            diagnostics: &mut $parser.diagnostics,
+            feature_type: PhantomData,
+        })?
+    };
+}
+
+/// Visits the top level of the document, dispatching each known layer to the seed for its feature type
+struct AirportVisitor<'a> {
+    parser: &'a mut Parser,
+}
+
+impl<'de, 'a> Visitor<'de> for AirportVisitor<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of AMDB feature collections")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let parser = self.parser;
+        let airport = &mut parser.airport;
+
+        while let Some(name) = map.next_key::<String>()? {
+            let Some(layer) = Layer::from_name(&name) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+
+            let present = match layer {
+                Layer::AerodromeReferencePoint => {
+                    next_layer!(map, parser, AerodromeReferencePoint: Point => parser.aerodrome_reference_points)
+                },
+                Layer::ApronElement => next_layer!(map, parser, ApronElement: Polygon => airport.apron_elements),
+                Layer::Blastpad => next_layer!(map, parser, Blastpad: Polygon => airport.blastpads),
+                Layer::ConstructionArea => {
+                    next_layer!(map, parser, ConstructionArea: Polygon => airport.construction_areas)
+                },
+                Layer::DeicingArea => next_layer!(map, parser, DeicingArea: Polygon => airport.deicing_areas),
+                Layer::FinalApproachAndTakeoffArea => {
+                    next_layer!(map, parser, FinalApproachAndTakeoffArea: Polygon => airport.final_approach_and_takeoff_areas)
+                },
+                Layer::FrequencyArea => next_layer!(map, parser, FrequencyArea: Polygon => airport.frequency_areas),
+                Layer::Hotspot => next_layer!(map, parser, Hotspot: Polygon => airport.hotspots),
+                Layer::LandAndHoldShortOperationLocation => {
+                    next_layer!(map, parser, LandAndHoldShortOperationLocation: LineString => airport.land_and_hold_short_operation_locations)
+                },
+                Layer::PaintedCenterline => {
+                    next_layer!(map, parser, PaintedCenterline: LineString => airport.painted_centerlines)
+                },
+                Layer::ParkingStandArea => {
+                    next_layer!(map, parser, ParkingStandArea: Polygon => airport.parking_stand_areas)
+                },
+                Layer::ParkingStandLocation => {
+                    next_layer!(map, parser, ParkingStandLocation: Point => airport.parking_stand_locations)
+                },
+                Layer::RunwayDisplacedArea => {
+                    next_layer!(map, parser, RunwayDisplacedArea: Polygon => airport.runway_displaced_areas)
+                },
+                Layer::RunwayElement => next_layer!(map, parser, RunwayElement: Polygon => airport.runway_elements),
+                Layer::RunwayExitLine => {
+                    next_layer!(map, parser, RunwayExitLine: LineString => airport.runway_exit_lines)
+                },
+                Layer::RunwayIntersection => {
+                    next_layer!(map, parser, RunwayIntersection: Polygon => airport.runway_intersections)
+                },
+                Layer::RunwayMarking => next_layer!(map, parser, RunwayMarking: Polygon => airport.runway_markings),
+                Layer::RunwayShoulder => next_layer!(map, parser, RunwayShoulder: Polygon => airport.runway_shoulders),
+                Layer::RunwayThreshold => next_layer!(map, parser, RunwayThreshold: Point => airport.runway_thresholds),
+                Layer::ServiceRoad => next_layer!(map, parser, ServiceRoad: Polygon => airport.service_roads),
+                Layer::StandGuidanceLine => {
+                    next_layer!(map, parser, StandGuidanceLine: LineString => airport.stand_guidance_lines)
+                },
+                Layer::Stopway => next_layer!(map, parser, Stopway: Polygon => airport.stopways),
+                Layer::TaxiwayElement => next_layer!(map, parser, TaxiwayElement: Polygon => airport.taxiway_elements),
+                Layer::TaxiwayGuidanceLine => {
+                    next_layer!(map, parser, TaxiwayGuidanceLine: LineString => airport.taxiway_guidance_lines)
+                },
+                Layer::TaxiwayHoldingPosition => {
+                    next_layer!(map, parser, TaxiwayHoldingPosition: LineString => airport.taxiway_holding_positions)
+                },
+                Layer::TaxiwayIntersectionMarking => {
+                    next_layer!(map, parser, TaxiwayIntersectionMarking: LineString => airport.taxiway_intersection_markings)
+                },
+                Layer::TaxiwayShoulder => {
+                    next_layer!(map, parser, TaxiwayShoulder: Polygon => airport.taxiway_shoulders)
+                },
+                Layer::TouchdownLiftoffArea => {
+                    next_layer!(map, parser, TouchdownLiftoffArea: Polygon => airport.touchdown_liftoff_areas)
+                },
+                Layer::VerticalLineStructure => {
+                    next_layer!(map, parser, VerticalLineStructure: LineString => airport.vertical_line_structures)
+                },
+                Layer::VerticalPointStructure => {
+                    next_layer!(map, parser, VerticalPointStructure: Point => airport.vertical_point_structures)
+                },
+                Layer::VerticalPolygonalStructure => {
+                    next_layer!(map, parser, VerticalPolygonalStructure: Polygon => airport.vertical_polygonal_structures)
+                },
+                Layer::Water => next_layer!(map, parser, Water: Polygon => airport.water),
+            };
+
+            if present {
+                airport.present_layers.insert(layer);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Deserializes the feature collection of a single layer, converting its features into `O` and appending them to
+/// `features`. Evaluates to whether the layer was present, treating a null feature collection as absent.
+struct LayerSeed<'a, T, O> {
+    layer: Layer,
+    features: &'a mut Vec<O>,
+    diagnostics: &'a mut Diagnostics,
+    feature_type: PhantomData<T>,
+}
+
+impl<'de, 'a, T, O> DeserializeSeed<'de> for LayerSeed<'a, T, O>
+where
+    T: DeserializeOwned + Feature,
+    O: TryFrom<T>,
+    PropertyError: From<O::Error>,
+{
+    type Value = bool;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, 'a, T, O> Visitor<'de> for LayerSeed<'a, T, O>
+where
+    T: DeserializeOwned + Feature,
+    O: TryFrom<T>,
+    PropertyError: From<O::Error>,
+{
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a feature collection for layer {}", self.layer)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(false)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(false)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "features" {
+                map.next_value_seed(FeaturesSeed {
+                    layer: self.layer,
+                    features: &mut *self.features,
+                    diagnostics: &mut *self.diagnostics,
+                    feature_type: PhantomData::<T>,
+                })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Deserializes the array of features of a single layer
+struct FeaturesSeed<'a, T, O> {
+    layer: Layer,
+    features: &'a mut Vec<O>,
+    diagnostics: &'a mut Diagnostics,
+    feature_type: PhantomData<T>,
+}
+
+impl<'de, 'a, T, O> DeserializeSeed<'de> for FeaturesSeed<'a, T, O>
+where
+    T: DeserializeOwned + Feature,
+    O: TryFrom<T>,
+    PropertyError: From<O::Error>,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a, T, O> Visitor<'de> for FeaturesSeed<'a, T, O>
+where
+    T: DeserializeOwned + Feature,
+    O: TryFrom<T>,
+    PropertyError: From<O::Error>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of features for layer {}", self.layer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut index = 0;
+
+        while let Some(feature) = seq.next_element::<serde_json::Value>()? {
+            match parse_feature(self.layer, index, feature, &self.diagnostics.options) {
+                Ok(feature) => self.features.push(feature),
+                Err(e) if self.diagnostics.options.lenient => self.diagnostics.warnings.push(e),
+                Err(e) => {
+                    let message = e.to_string();
+                    self.diagnostics.error = Some(e);
+
+                    return Err(de::Error::custom(message));
+                },
+            }
+
+            index += 1;
+        }
+
+        Ok(())
+    }
+}
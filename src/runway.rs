@@ -0,0 +1,298 @@
+use std::sync::OnceLock;
+
+use geo::{Coord, HaversineBearing, HaversineDistance, Line, MultiPolygon, Point};
+
+use crate::{
+    geometry::{combine, dissolve, long_axis},
+    output_types::{
+        PaintedCenterline,
+        RunwayDesignator,
+        RunwayDisplacedArea,
+        RunwayElement,
+        RunwayId,
+        RunwayMarking,
+        RunwayShoulder,
+        RunwayThreshold,
+    },
+    Airport,
+};
+
+/// How far in degrees the magnetic bearing of a threshold may stray from the heading of its designator. Designators
+/// are rounded to the nearest ten degrees, and often lag behind the drift of magnetic variation.
+const BEARING_TOLERANCE: f64 = 10.0;
+
+/// One end of a runway
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunwayEnd {
+    pub designator: RunwayDesignator,
+    /// The id of the threshold at this end, if the airport has one
+    pub threshold: Option<u64>,
+}
+
+/// A problem with the features which describe a runway
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunwayIssue {
+    /// The designators of the runway id are not reciprocals of each other, such as `09.28`
+    NonReciprocalEnds,
+    /// The magnetic bearing of the threshold with this id is too far from the heading given by its designator, such
+    /// as a bearing of 250 degrees at runway end `27`
+    BearingMismatch { threshold: u64 },
+}
+
+/// A runway, joining the features which describe it through its runway id.
+///
+/// Related features are referenced by id, and can be resolved against the airport the runway was built from.
+#[derive(Debug, Clone)]
+pub struct Runway {
+    pub runway_id: RunwayId,
+    /// The ends of the runway, in the order they appear in the runway id
+    pub ends: [RunwayEnd; 2],
+    outline: OnceLock<MultiPolygon>,
+    /// The centerline of the runway from the first end to the second, if it could be determined
+    pub centerline: Option<Line>,
+    /// The length of the runway in metres
+    pub length: f64,
+    /// The width of the runway in metres
+    pub width: f64,
+    pub element_ids: Vec<u64>,
+    pub marking_ids: Vec<u64>,
+    pub shoulder_ids: Vec<u64>,
+    pub painted_centerline_ids: Vec<u64>,
+    pub displaced_area_ids: Vec<u64>,
+    /// Problems with the features of the runway. Empty if none were found.
+    pub issues: Vec<RunwayIssue>,
+}
+
+impl Runway {
+    /// Whether either end of the runway has the given designator
+    pub fn has_end(&self, designator: &RunwayDesignator) -> bool { self.runway_id.has_end(designator) }
+
+    /// The polygons of the runway elements taken together, without dissolving the edges they share. They are gathered
+    /// from the airport each time, rather than being held alongside the elements.
+    pub fn surface(&self, airport: &Airport) -> MultiPolygon {
+        combine(self.elements(airport).map(|element| &element.geometry))
+    }
+
+    /// The union of the runway elements, which is dissolved the first time it is asked for. The airport must be the one
+    /// the runway was built from.
+    pub fn outline(&self, airport: &Airport) -> &MultiPolygon {
+        self.outline.get_or_init(|| dissolve(&self.surface(airport)))
+    }
+
+    /// The thresholds of the runway, first end first
+    pub fn thresholds<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayThreshold> {
+        self.ends.iter().filter_map(|end| {
+            let id = end.threshold?;
+            airport.runway_thresholds.iter().find(|threshold| threshold.id == id)
+        })
+    }
+
+    /// The takeoff run available in metres when beginning the takeoff roll from `point` on the runway end with
+    /// `designator`, counted from the end of the runway rather than its threshold, which may be displaced. None if the
+    /// runway has no such end, the end has no threshold or the runway has no centerline.
+    pub fn takeoff_run_available_from(
+        &self, airport: &Airport, designator: &RunwayDesignator, point: Coord,
+    ) -> Option<f64> {
+        let index = self.ends.iter().position(|end| &end.designator == designator)?;
+        let threshold = self
+            .thresholds(airport)
+            .find(|threshold| Some(threshold.id) == self.ends[index].threshold)?;
+        let centerline = self.centerline?;
+
+        let runway_end = match index {
+            0 => centerline.start,
+            _ => centerline.end,
+        };
+
+        Some(threshold.takeoff_run_available_from(runway_end, point))
+    }
+
+    pub fn elements<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayElement> {
+        airport
+            .runway_elements
+            .iter()
+            .filter(|element| self.element_ids.contains(&element.id))
+    }
+
+    pub fn markings<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayMarking> {
+        airport
+            .runway_markings
+            .iter()
+            .filter(|marking| self.marking_ids.contains(&marking.id))
+    }
+
+    pub fn shoulders<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayShoulder> {
+        airport
+            .runway_shoulders
+            .iter()
+            .filter(|shoulder| self.shoulder_ids.contains(&shoulder.id))
+    }
+
+    pub fn painted_centerlines<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a PaintedCenterline> {
+        airport
+            .painted_centerlines
+            .iter()
+            .filter(|centerline| self.painted_centerline_ids.contains(&centerline.id))
+    }
+
+    pub fn displaced_areas<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayDisplacedArea> {
+        airport
+            .runway_displaced_areas
+            .iter()
+            .filter(|area| self.displaced_area_ids.contains(&area.id))
+    }
+
+    fn new(airport: &Airport, runway_id: RunwayId) -> Self {
+        let end = |designator: &RunwayDesignator| RunwayEnd {
+            designator: designator.clone(),
+            threshold: airport
+                .runway_thresholds
+                .iter()
+                .find(|threshold| threshold.designator().as_ref() == Ok(designator))
+                .map(|threshold| threshold.id),
+        };
+
+        let elements: Vec<_> = airport
+            .runway_elements
+            .iter()
+            .filter(|element| element.runway_id.same_runway(&runway_id))
+            .collect();
+
+        let mut runway = Self {
+            ends: [end(&runway_id.first), end(&runway_id.second)],
+            outline: OnceLock::new(),
+            centerline: None,
+            length: 0.0,
+            width: 0.0,
+            element_ids: elements.iter().map(|element| element.id).collect(),
+            marking_ids: airport
+                .runway_markings
+                .iter()
+                .filter(|marking| marking.runway_id.same_runway(&runway_id))
+                .map(|marking| marking.id)
+                .collect(),
+            shoulder_ids: airport
+                .runway_shoulders
+                .iter()
+                .filter(|shoulder| shoulder.runway_id.same_runway(&runway_id))
+                .map(|shoulder| shoulder.id)
+                .collect(),
+            painted_centerline_ids: airport
+                .painted_centerlines
+                .iter()
+                .filter(|centerline| centerline.runway_id.same_runway(&runway_id))
+                .map(|centerline| centerline.id)
+                .collect(),
+            displaced_area_ids: airport
+                .runway_displaced_areas
+                .iter()
+                .filter(|area| {
+                    RunwayDesignator::try_from(area.threshold_id.as_str())
+                        .is_ok_and(|designator| runway_id.has_end(&designator))
+                })
+                .map(|area| area.id)
+                .collect(),
+            issues: Vec::new(),
+            runway_id,
+        };
+
+        if !runway.runway_id.is_reciprocal() {
+            runway.issues.push(RunwayIssue::NonReciprocalEnds);
+        }
+
+        let mismatched: Vec<_> = runway
+            .thresholds(airport)
+            .filter(|threshold| {
+                threshold
+                    .designator()
+                    .is_ok_and(|designator| designator.heading().is_some())
+                    && !threshold.matches_designator(BEARING_TOLERANCE)
+            })
+            .map(|threshold| RunwayIssue::BearingMismatch {
+                threshold: threshold.id,
+            })
+            .collect();
+        runway.issues.extend(mismatched);
+
+        let locations: Vec<_> = runway.thresholds(airport).map(|threshold| threshold.location).collect();
+
+        if let Some(axis) = long_axis(&combine(elements.iter().map(|element| &element.geometry))) {
+            runway.centerline = Some(runway.orient(axis.line, airport));
+            runway.length = axis.length;
+            runway.width = axis.width;
+        } else if let [first, second] = locations[..] {
+            runway.centerline = Some(Line::new(first, second));
+            runway.length = Point::from(first).haversine_distance(&Point::from(second));
+        }
+
+        runway
+    }
+
+    /// Orients a line along the runway so it runs from the first end to the second. The thresholds are used where
+    /// present, falling back to the heading given by the designator of the first end.
+    fn orient(&self, line: Line, airport: &Airport) -> Line {
+        let distance = |a: Coord, b: Coord| Point::from(a).haversine_distance(&Point::from(b));
+        let reversed = Line::new(line.end, line.start);
+
+        for (index, end) in self.ends.iter().enumerate() {
+            let Some(threshold) = end
+                .threshold
+                .and_then(|id| airport.runway_thresholds.iter().find(|threshold| threshold.id == id))
+            else {
+                continue;
+            };
+
+            let closer_to_start = distance(threshold.location, line.start) <= distance(threshold.location, line.end);
+
+            return if closer_to_start == (index == 0) {
+                line
+            } else {
+                reversed
+            };
+        }
+
+        match self.ends[0].designator.heading() {
+            Some(heading) => {
+                let bearing = Point::from(line.start).haversine_bearing(Point::from(line.end));
+
+                if (bearing - heading).to_radians().cos() >= 0.0 {
+                    line
+                } else {
+                    reversed
+                }
+            },
+            None => line,
+        }
+    }
+}
+
+/// Builds the runways of an airport from every runway id referenced by its runway elements, markings, shoulders and
+/// painted centerlines, in the order they first appear. Features naming a runway from either end belong to the same
+/// runway, whose ends are ordered as the runway id first seen names them.
+pub(crate) fn build_runways(airport: &Airport) -> Vec<Runway> {
+    let mut runway_ids: Vec<&RunwayId> = Vec::new();
+
+    let referenced = airport
+        .runway_elements
+        .iter()
+        .map(|element| &element.runway_id)
+        .chain(airport.runway_markings.iter().map(|marking| &marking.runway_id))
+        .chain(airport.runway_shoulders.iter().map(|shoulder| &shoulder.runway_id))
+        .chain(
+            airport
+                .painted_centerlines
+                .iter()
+                .map(|centerline| &centerline.runway_id),
+        );
+
+    for runway_id in referenced {
+        if !runway_ids.iter().any(|known| known.same_runway(runway_id)) {
+            runway_ids.push(runway_id);
+        }
+    }
+
+    runway_ids
+        .into_iter()
+        .map(|runway_id| Runway::new(airport, runway_id.clone()))
+        .collect()
+}
@@ -0,0 +1,241 @@
+use geo::{Coord, EuclideanDistance, HaversineBearing, HaversineDistance, Intersects, Line, Point};
+
+use crate::{
+    enums::{Direction, Status},
+    geometry::{chain_lines, LocalProjection},
+    layer::Layer,
+    output_types::{AnyFeature, RunwayDesignator, RunwayExitLine, RunwayId},
+    runway::Runway,
+    spatial_index::SpatialIndex,
+    Airport,
+};
+
+/// The largest angle in degrees from the landing direction at which an exit counts as a rapid exit
+const RAPID_EXIT_MAX_ANGLE: f64 = 45.0;
+
+/// The smallest angle in degrees from the landing direction at which an exit turns back against it
+const REVERSE_EXIT_MIN_ANGLE: f64 = 120.0;
+
+/// The distance in metres within which the far end of an exit line is taken to meet a taxiway guidance line
+const TAXIWAY_LINK_DISTANCE: f64 = 5.0;
+
+/// The distance in metres along an exit line from the runway over which the direction it leaves in is measured, so
+/// that the curve of a rapid exit onto a taxiway further away does not count
+const EXIT_BEARING_DISTANCE: f64 = 30.0;
+
+/// How sharply an exit turns off the runway, relative to the landing direction
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExitKind {
+    /// Angled forward off the runway, so that it can be taken at speed
+    RapidExit,
+    /// Turning off at around a right angle
+    RightAngle,
+    /// Angled back against the landing direction, so that it can only be taken after turning around
+    Reverse,
+}
+
+/// An exit off a runway, as seen when landing in one direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunwayExit {
+    pub exit_line_id: u64,
+    /// The taxiway the exit leads to: the one the exit line is designated as, or otherwise the one whose guidance
+    /// line it meets away from the runway
+    pub taxiway_id: Option<String>,
+    /// Where the exit line leaves the runway
+    pub location: Coord,
+    /// The distance in metres along the runway from the landing threshold to where the exit leaves it
+    pub distance_from_threshold: f64,
+    /// The angle in degrees between the landing direction and the direction the exit leaves the runway in, over its
+    /// first stretch, from 0 for straight ahead to 180 for straight back
+    pub angle: f64,
+    pub kind: ExitKind,
+    /// Whether the exit can be taken when landing in this direction. It must lie within the landing distance
+    /// available, be open, allow travel away from the runway and not turn back against the landing direction.
+    pub usable: bool,
+}
+
+/// The exits off a runway when landing in one direction, in the order they are reached
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExitTable {
+    pub runway_id: RunwayId,
+    /// The designator of the runway end landed on
+    pub landing_direction: RunwayDesignator,
+    /// The id of the threshold landed on, if the airport has one
+    pub threshold_id: Option<u64>,
+    pub exits: Vec<RunwayExit>,
+}
+
+/// The distance in metres from `origin` to `point` along a bearing
+fn distance_along(origin: Coord, bearing: f64, point: Coord) -> f64 {
+    let origin = Point::from(origin);
+    let point = Point::from(point);
+    let angle = (origin.haversine_bearing(point) - bearing).to_radians();
+
+    origin.haversine_distance(&point) * angle.cos()
+}
+
+/// The point a distance in metres along a line, or its end if the line is shorter
+fn point_along(coords: &[Coord], mut remaining: f64) -> Coord {
+    for w in coords.windows(2) {
+        let length = Point::from(w[0]).haversine_distance(&Point::from(w[1]));
+
+        if length >= remaining && length > 0.0 {
+            return w[0] + (w[1] - w[0]) * (remaining / length);
+        }
+
+        remaining -= length;
+    }
+
+    coords[coords.len() - 1]
+}
+
+/// The exit line as a single chain running from the runway, along with whether the line itself runs away from the
+/// runway
+fn exit_chain(
+    exit_line: &RunwayExitLine, centerline: Line, projection: &LocalProjection,
+) -> Option<(Vec<Coord>, bool)> {
+    let centerline = Line::new(projection.project(centerline.start), projection.project(centerline.end));
+    let offset = |coord: Coord| Point::from(projection.project(coord)).euclidean_distance(&centerline);
+
+    chain_lines(&exit_line.geometry)
+        .0
+        .into_iter()
+        .filter_map(|chain| {
+            let (&first, &last) = (chain.0.first()?, chain.0.last()?);
+
+            Some(match offset(first) <= offset(last) {
+                true => (offset(first), (chain.0, true)),
+                false => (offset(last), (chain.0.into_iter().rev().collect(), false)),
+            })
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, chain)| chain)
+}
+
+/// The exit tables of a runway, one for landing on each end
+fn exit_tables(airport: &Airport, runway: &Runway, index: &SpatialIndex) -> Vec<ExitTable> {
+    let Some(centerline) = runway.centerline else {
+        return Vec::new();
+    };
+
+    let projection = LocalProjection::new(centerline.start);
+
+    let exit_lines: Vec<_> = airport
+        .runway_exit_lines
+        .iter()
+        .filter(|exit_line| {
+            runway
+                .elements(airport)
+                .any(|element| exit_line.geometry.intersects(&element.geometry))
+        })
+        .filter_map(|exit_line| {
+            let (chain, runs_away) = exit_chain(exit_line, centerline, &projection)?;
+            let (location, far_end) = (chain[0], chain[chain.len() - 1]);
+            let leaving_towards = point_along(&chain, EXIT_BEARING_DISTANCE);
+
+            let taxiway_id = exit_line.taxiway_id.clone().or_else(|| {
+                index
+                    .within_distance(far_end, TAXIWAY_LINK_DISTANCE, &[Layer::TaxiwayGuidanceLine])
+                    .into_iter()
+                    .find_map(|(feature, _)| match feature {
+                        AnyFeature::TaxiwayGuidanceLine(line) => line.taxiway_id.clone(),
+                        _ => None,
+                    })
+            });
+
+            Some((exit_line, location, leaving_towards, runs_away, taxiway_id))
+        })
+        .collect();
+
+    let forwards = Point::from(centerline.start).haversine_bearing(Point::from(centerline.end));
+
+    runway
+        .ends
+        .iter()
+        .enumerate()
+        .map(|(end_index, end)| {
+            let threshold = end
+                .threshold
+                .and_then(|id| airport.runway_thresholds.iter().find(|threshold| threshold.id == id));
+
+            let (origin, centerline_bearing) = match end_index {
+                0 => (centerline.start, forwards),
+                _ => (centerline.end, forwards + 180.0),
+            };
+
+            let bearing = threshold.map_or(centerline_bearing, |threshold| threshold.true_bearing);
+
+            let mut exits: Vec<_> = exit_lines
+                .iter()
+                .map(|(exit_line, location, leaving_towards, runs_away, taxiway_id)| {
+                    let (location, runs_away) = (*location, *runs_away);
+
+                    let distance_from_threshold = match threshold {
+                        Some(threshold) => threshold.distance_along_runway(location),
+                        None => distance_along(origin, bearing, location),
+                    };
+
+                    let exit_bearing = Point::from(location).haversine_bearing(Point::from(*leaving_towards));
+                    let angle = (exit_bearing - bearing).rem_euclid(360.0);
+                    let angle = angle.min(360.0 - angle);
+
+                    let kind = match angle {
+                        angle if angle <= RAPID_EXIT_MAX_ANGLE => ExitKind::RapidExit,
+                        angle if angle < REVERSE_EXIT_MIN_ANGLE => ExitKind::RightAngle,
+                        _ => ExitKind::Reverse,
+                    };
+
+                    let within_landing_distance = distance_from_threshold > 0.0
+                        && threshold.is_none_or(|threshold| {
+                            threshold.landing_distance_available <= 0.0
+                                || distance_from_threshold <= threshold.landing_distance_available
+                        });
+
+                    let direction_allowed = match exit_line.direction {
+                        Direction::StartToEndpoint => runs_away,
+                        Direction::EndToStartpoint => !runs_away,
+                        _ => true,
+                    };
+
+                    RunwayExit {
+                        exit_line_id: exit_line.id,
+                        taxiway_id: taxiway_id.clone(),
+                        location,
+                        distance_from_threshold,
+                        angle,
+                        kind,
+                        usable: within_landing_distance
+                            && direction_allowed
+                            && exit_line.status != Status::Closed
+                            && kind != ExitKind::Reverse,
+                    }
+                })
+                .collect();
+
+            exits.sort_by(|a, b| a.distance_from_threshold.total_cmp(&b.distance_from_threshold));
+
+            ExitTable {
+                runway_id: runway.runway_id.clone(),
+                landing_direction: end.designator.clone(),
+                threshold_id: threshold.map(|threshold| threshold.id),
+                exits,
+            }
+        })
+        .collect()
+}
+
+impl Airport {
+    /// Builds a table of the exits off each runway for each landing direction, relating runway exit lines to the
+    /// runways they leave from.
+    ///
+    /// Distances and the landing direction are taken from the runway thresholds, falling back to the runway centerline
+    /// where a runway end has no threshold. Runways without a centerline have no tables.
+    pub fn runway_exit_tables(&self) -> Vec<ExitTable> {
+        let index = self.spatial_index();
+
+        self.runways()
+            .iter()
+            .flat_map(|runway| exit_tables(self, runway, &index))
+            .collect()
+    }
+}
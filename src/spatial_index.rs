@@ -0,0 +1,114 @@
+use geo::{BoundingRect, Coord, EuclideanDistance, Geometry, Intersects, MapCoords, Point, Rect};
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+
+use crate::{
+    geometry::LocalProjection,
+    layer::Layer,
+    output_types::{AmdbElement, AnyFeature},
+    Airport,
+};
+
+/// A feature stored in the index along with its geometry in metres
+struct IndexedFeature<'a> {
+    feature: AnyFeature<'a>,
+    geometry: Geometry,
+    envelope: AABB<[f64; 2]>,
+}
+
+impl RTreeObject for IndexedFeature<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope { self.envelope }
+}
+
+impl PointDistance for IndexedFeature<'_> {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 { self.geometry.euclidean_distance(&Point::from(*point)).powi(2) }
+}
+
+/// An R-tree over every feature of an airport, for finding the features at or near a location.
+///
+/// Locations are given as longitude and latitude and distances are in metres. Queries take the layers to match, with
+/// an empty slice matching every layer.
+pub struct SpatialIndex<'a> {
+    projection: LocalProjection,
+    tree: RTree<IndexedFeature<'a>>,
+}
+
+impl<'a> SpatialIndex<'a> {
+    /// Builds an index over every feature of the airport
+    pub fn new(airport: &'a Airport) -> Self {
+        let projection = LocalProjection::new(airport.aerodrome_reference_point.location);
+
+        let features = airport
+            .features()
+            .filter_map(|feature| {
+                let geometry = feature.geometry().map_coords(|coord| projection.project(coord));
+                let rect = geometry.bounding_rect()?;
+
+                Some(IndexedFeature {
+                    feature,
+                    geometry,
+                    envelope: AABB::from_corners(rect.min().into(), rect.max().into()),
+                })
+            })
+            .collect();
+
+        Self {
+            projection,
+            tree: RTree::bulk_load(features),
+        }
+    }
+
+    /// The features whose geometry contains or touches a location
+    pub fn at_point(&self, location: Coord, layers: &[Layer]) -> Vec<AnyFeature<'a>> {
+        let point = self.projection.project(location);
+
+        self.tree
+            .locate_in_envelope_intersecting(&AABB::from_point(point.into()))
+            .filter(|indexed| matches_layer(indexed, layers) && indexed.geometry.intersects(&point))
+            .map(|indexed| indexed.feature)
+            .collect()
+    }
+
+    /// The features whose geometry intersects a box given by two opposite corners
+    pub fn in_rect(&self, rect: Rect, layers: &[Layer]) -> Vec<AnyFeature<'a>> {
+        let rect = Rect::new(self.projection.project(rect.min()), self.projection.project(rect.max()));
+
+        self.tree
+            .locate_in_envelope_intersecting(&AABB::from_corners(rect.min().into(), rect.max().into()))
+            .filter(|indexed| matches_layer(indexed, layers) && indexed.geometry.intersects(&rect))
+            .map(|indexed| indexed.feature)
+            .collect()
+    }
+
+    /// The `count` features nearest to a location along with their distances, nearest first
+    pub fn nearest(&self, location: Coord, count: usize, layers: &[Layer]) -> Vec<(AnyFeature<'a>, f64)> {
+        let point: [f64; 2] = self.projection.project(location).into();
+
+        self.tree
+            .nearest_neighbor_iter_with_distance_2(&point)
+            .filter(|(indexed, _)| matches_layer(indexed, layers))
+            .take(count)
+            .map(|(indexed, distance_2)| (indexed.feature, distance_2.sqrt()))
+            .collect()
+    }
+
+    /// The features within `distance` metres of a location along with their distances, nearest first
+    pub fn within_distance(&self, location: Coord, distance: f64, layers: &[Layer]) -> Vec<(AnyFeature<'a>, f64)> {
+        let point: [f64; 2] = self.projection.project(location).into();
+
+        let mut features: Vec<_> = self
+            .tree
+            .locate_within_distance(point, distance.powi(2))
+            .filter(|indexed| matches_layer(indexed, layers))
+            .map(|indexed| (indexed.feature, indexed.distance_2(&point).sqrt()))
+            .collect();
+
+        features.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        features
+    }
+}
+
+fn matches_layer(indexed: &IndexedFeature, layers: &[Layer]) -> bool {
+    layers.is_empty() || layers.contains(&indexed.feature.layer())
+}
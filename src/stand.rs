@@ -0,0 +1,252 @@
+use geo::{Coord, HaversineBearing, HaversineDistance, Intersects, LineString, Point};
+
+use crate::{
+    enums::Availability,
+    geometry::chain_lines,
+    output_types::{ParkingStandArea, ParkingStandLocation, StandGuidanceLine},
+    Airport,
+};
+
+/// The distance in metres within which the lead-in line is expected to reach the stop point
+const LEAD_IN_TOLERANCE: f64 = 10.0;
+
+/// The services available at a stand, as given by its parking stand area
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandServices {
+    pub jetway: Availability,
+    pub fuel: String,
+    pub ground_power: Availability,
+    pub towing: Availability,
+}
+
+/// A piece of a stand which is missing or disagrees with the others
+#[derive(Debug, Clone, PartialEq)]
+pub enum StandIssue {
+    MissingLocation,
+    MissingArea,
+    MissingGuidanceLine,
+    /// Another parking stand location with the same stand id, which was ignored
+    DuplicateLocation(u64),
+    /// Another parking stand area with the same stand id, which was ignored
+    DuplicateArea(u64),
+    /// The stop point lies outside the parking stand area
+    LocationOutsideArea,
+    /// The lead-in line does not end at the stop point
+    LeadInLineMissesLocation,
+    /// The pieces of the stand name different terminals
+    TerminalMismatch(Vec<String>),
+}
+
+/// A parking stand, merging the location, area and guidance lines which share its stand id.
+///
+/// Related features are referenced by id, and can be resolved against the airport the stand was built from.
+#[derive(Debug, Clone)]
+pub struct Stand {
+    pub stand_id: String,
+    pub location_id: Option<u64>,
+    pub area_id: Option<u64>,
+    pub guidance_line_ids: Vec<u64>,
+    /// The point the aircraft stops at, as given by the parking stand location
+    pub stop_point: Option<Coord>,
+    /// The stand guidance lines joined into a single line ending at the stop point
+    pub lead_in_line: Option<LineString>,
+    /// The true heading of an aircraft parked on the stand, taken from the end of the lead-in line
+    pub heading: Option<f64>,
+    pub apron_id: Option<String>,
+    pub terminal_name: Option<String>,
+    pub services: Option<StandServices>,
+    pub aircraft_types: Vec<String>,
+    pub restricted_aircraft_types: Vec<String>,
+    /// Pieces of the stand which are missing or inconsistent. Empty if the stand is complete.
+    pub issues: Vec<StandIssue>,
+}
+
+impl Stand {
+    pub fn location<'a>(&self, airport: &'a Airport) -> Option<&'a ParkingStandLocation> {
+        let id = self.location_id?;
+        airport
+            .parking_stand_locations
+            .iter()
+            .find(|location| location.id == id)
+    }
+
+    pub fn area<'a>(&self, airport: &'a Airport) -> Option<&'a ParkingStandArea> {
+        let id = self.area_id?;
+        airport.parking_stand_areas.iter().find(|area| area.id == id)
+    }
+
+    pub fn guidance_lines<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a StandGuidanceLine> {
+        airport
+            .stand_guidance_lines
+            .iter()
+            .filter(|guidance_line| self.guidance_line_ids.contains(&guidance_line.id))
+    }
+
+    fn new(airport: &Airport, stand_id: String) -> Self {
+        let on_stand = |id: &Option<String>| id.as_ref() == Some(&stand_id);
+        let mut issues = Vec::new();
+
+        let mut locations = airport
+            .parking_stand_locations
+            .iter()
+            .filter(|location| on_stand(&location.stand_id));
+        let location = locations.next();
+        issues.extend(locations.map(|location| StandIssue::DuplicateLocation(location.id)));
+
+        let mut areas = airport
+            .parking_stand_areas
+            .iter()
+            .filter(|area| on_stand(&area.stand_id));
+        let area = areas.next();
+        issues.extend(areas.map(|area| StandIssue::DuplicateArea(area.id)));
+
+        let guidance_lines: Vec<_> = airport
+            .stand_guidance_lines
+            .iter()
+            .filter(|guidance_line| on_stand(&guidance_line.stand_id))
+            .collect();
+
+        let stop_point = location.map(|location| location.location);
+        let lead_in_line = lead_in_line(&guidance_lines, stop_point);
+
+        let heading = lead_in_line.as_ref().and_then(|line| match line.0[..] {
+            [.., previous, last] => Some(
+                Point::from(previous)
+                    .haversine_bearing(Point::from(last))
+                    .rem_euclid(360.0),
+            ),
+            _ => None,
+        });
+
+        if location.is_none() {
+            issues.push(StandIssue::MissingLocation);
+        }
+
+        if area.is_none() {
+            issues.push(StandIssue::MissingArea);
+        }
+
+        if guidance_lines.is_empty() {
+            issues.push(StandIssue::MissingGuidanceLine);
+        }
+
+        if let (Some(area), Some(stop_point)) = (area, stop_point) {
+            if !area.geometry.intersects(&stop_point) {
+                issues.push(StandIssue::LocationOutsideArea);
+            }
+        }
+
+        if let (Some(line), Some(stop_point)) = (&lead_in_line, stop_point) {
+            if let Some(&end) = line.0.last() {
+                if Point::from(end).haversine_distance(&Point::from(stop_point)) > LEAD_IN_TOLERANCE {
+                    issues.push(StandIssue::LeadInLineMissesLocation);
+                }
+            }
+        }
+
+        let mut terminal_names: Vec<String> = Vec::new();
+
+        let named = location
+            .and_then(|location| location.terminal_name.as_ref())
+            .into_iter()
+            .chain(area.and_then(|area| area.terminal_name.as_ref()))
+            .chain(guidance_lines.iter().filter_map(|line| line.terminal_name.as_ref()));
+
+        for terminal_name in named {
+            if !terminal_names.contains(terminal_name) {
+                terminal_names.push(terminal_name.clone());
+            }
+        }
+
+        if terminal_names.len() > 1 {
+            issues.push(StandIssue::TerminalMismatch(terminal_names.clone()));
+        }
+
+        Self {
+            location_id: location.map(|location| location.id),
+            area_id: area.map(|area| area.id),
+            guidance_line_ids: guidance_lines.iter().map(|guidance_line| guidance_line.id).collect(),
+            stop_point,
+            lead_in_line,
+            heading,
+            apron_id: area.and_then(|area| area.apron_id.clone()),
+            terminal_name: terminal_names.into_iter().next(),
+            services: area.map(|area| StandServices {
+                jetway: area.jetway,
+                fuel: area.fuel.clone(),
+                ground_power: area.ground_power,
+                towing: area.towing,
+            }),
+            aircraft_types: location
+                .map(|location| location.aircraft_types.clone())
+                .unwrap_or_default(),
+            restricted_aircraft_types: area
+                .map(|area| area.restricted_aircraft_types.clone())
+                .unwrap_or_default(),
+            issues,
+            stand_id,
+        }
+    }
+}
+
+/// Joins the guidance lines of a stand and picks the chain which comes closest to the stop point, oriented so that it
+/// ends there
+fn lead_in_line(guidance_lines: &[&StandGuidanceLine], stop_point: Option<Coord>) -> Option<LineString> {
+    let chains = chain_lines(guidance_lines.iter().flat_map(|guidance_line| &guidance_line.geometry));
+
+    let Some(stop_point) = stop_point else {
+        return chains.0.into_iter().next();
+    };
+
+    let distance = |coord: &Coord| Point::from(*coord).haversine_distance(&Point::from(stop_point));
+
+    chains
+        .0
+        .into_iter()
+        .filter_map(|mut chain| {
+            let start = distance(chain.0.first()?);
+            let end = distance(chain.0.last()?);
+
+            if start < end {
+                chain.0.reverse();
+            }
+
+            Some((start.min(end), chain))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, chain)| chain)
+}
+
+/// Builds the stands of an airport from every stand id referenced by its parking stand locations, parking stand areas
+/// and stand guidance lines, in the order they first appear. Features without a known stand id belong to no stand.
+pub(crate) fn build_stands(airport: &Airport) -> Vec<Stand> {
+    let mut stand_ids: Vec<&String> = Vec::new();
+
+    let referenced = airport
+        .parking_stand_locations
+        .iter()
+        .filter_map(|location| location.stand_id.as_ref())
+        .chain(
+            airport
+                .parking_stand_areas
+                .iter()
+                .filter_map(|area| area.stand_id.as_ref()),
+        )
+        .chain(
+            airport
+                .stand_guidance_lines
+                .iter()
+                .filter_map(|line| line.stand_id.as_ref()),
+        );
+
+    for stand_id in referenced {
+        if !stand_ids.contains(&stand_id) {
+            stand_ids.push(stand_id);
+        }
+    }
+
+    stand_ids
+        .into_iter()
+        .map(|stand_id| Stand::new(airport, stand_id.clone()))
+        .collect()
+}
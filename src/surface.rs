@@ -0,0 +1,190 @@
+use std::fmt;
+
+use geo::{Coord, EuclideanDistance, HaversineBearing, Intersects, Line, Point};
+
+use crate::{
+    geometry::LocalProjection,
+    output_types::{RunwayDesignator, RunwayId},
+    runway::Runway,
+    Airport,
+};
+
+/// The surface an aircraft is on, as found by [Airport::classify_position].
+///
+/// Where surfaces overlap, runways take precedence over taxiways, taxiways over stands and stands over aprons.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SurfacePosition {
+    Runway {
+        runway_id: RunwayId,
+        /// The direction the aircraft is heading along the runway, given as the designator of the end it is moving
+        /// away from. Only known when a heading was given.
+        direction: Option<RunwayDesignator>,
+    },
+    /// A taxiway, with its designator if it has one
+    Taxiway(Option<String>),
+    Stand(String),
+    /// An apron, with its id if it has one
+    Apron(Option<String>),
+    OffPavement,
+}
+
+impl fmt::Display for SurfacePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SurfacePosition::Runway {
+                runway_id,
+                direction: Some(direction),
+            } => write!(f, "on runway {runway_id} heading {direction}"),
+            SurfacePosition::Runway { runway_id, .. } => write!(f, "on runway {runway_id}"),
+            SurfacePosition::Taxiway(Some(designator)) => write!(f, "on taxiway {designator}"),
+            SurfacePosition::Taxiway(None) => f.write_str("on taxiway"),
+            SurfacePosition::Stand(stand_id) => write!(f, "on stand {stand_id}"),
+            SurfacePosition::Apron(Some(apron_id)) => write!(f, "on apron {apron_id}"),
+            SurfacePosition::Apron(None) => f.write_str("on apron"),
+            SurfacePosition::OffPavement => f.write_str("off pavement"),
+        }
+    }
+}
+
+/// How closely a line runs along a heading, from 0 when square to it to 1 when parallel in either direction
+fn alignment(line: Line, heading: f64) -> f64 {
+    let bearing = Point::from(line.start).haversine_bearing(Point::from(line.end));
+
+    (bearing - heading).to_radians().cos().abs()
+}
+
+/// Picks the runway best aligned with the heading, or the first one without a heading
+fn pick_runway(runways: Vec<&Runway>, heading: Option<f64>) -> Option<&Runway> {
+    let Some(heading) = heading else {
+        return runways.into_iter().next();
+    };
+
+    runways.into_iter().max_by(|a, b| {
+        let score = |runway: &Runway| runway.centerline.map_or(0.0, |line| alignment(line, heading));
+        score(a).total_cmp(&score(b))
+    })
+}
+
+/// The direction along a runway given by a heading, as the designator of the end the aircraft is moving away from
+fn runway_direction(runway: &Runway, heading: f64) -> Option<RunwayDesignator> {
+    let centerline = runway.centerline?;
+    let bearing = Point::from(centerline.start).haversine_bearing(Point::from(centerline.end));
+
+    let [first, second] = &runway.ends;
+
+    Some(if (bearing - heading).to_radians().cos() >= 0.0 {
+        first.designator.clone()
+    } else {
+        second.designator.clone()
+    })
+}
+
+impl Airport {
+    /// Finds the surface an aircraft at `location` is on, such as a runway or a stand.
+    ///
+    /// The heading, in degrees true, picks between overlapping runways or taxiways by how closely they are aligned
+    /// with it, and gives the direction of travel along a runway.
+    pub fn classify_position(&self, location: Coord, heading: Option<f64>) -> SurfacePosition {
+        let runways: Vec<_> = self
+            .runways()
+            .iter()
+            .filter(|runway| {
+                runway
+                    .elements(self)
+                    .any(|element| element.geometry.intersects(&location))
+            })
+            .collect();
+
+        if let Some(runway) = pick_runway(runways, heading) {
+            return SurfacePosition::Runway {
+                runway_id: runway.runway_id.clone(),
+                direction: heading.and_then(|heading| runway_direction(runway, heading)),
+            };
+        }
+
+        let taxiways: Vec<_> = self
+            .taxiways()
+            .iter()
+            .filter(|taxiway| {
+                taxiway
+                    .elements(self)
+                    .any(|element| element.geometry.intersects(&location))
+            })
+            .collect();
+
+        let taxiway = match heading {
+            Some(heading) => {
+                let projection = LocalProjection::new(location);
+                let point = Point::from(projection.project(location));
+
+                // The alignment of the guidance line segment closest to the location
+                let score = |lines: &[Line]| {
+                    lines
+                        .iter()
+                        .map(|line| {
+                            let projected = Line::new(projection.project(line.start), projection.project(line.end));
+                            (point.euclidean_distance(&projected), alignment(*line, heading))
+                        })
+                        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                        .map_or(0.0, |(_, alignment)| alignment)
+                };
+
+                taxiways
+                    .into_iter()
+                    .map(|taxiway| {
+                        let lines: Vec<_> = taxiway.centerline.iter().flat_map(|line| line.lines()).collect();
+                        (score(&lines), taxiway)
+                    })
+                    .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                    .map(|(_, taxiway)| taxiway)
+            },
+            None => taxiways.into_iter().next(),
+        };
+
+        if let Some(taxiway) = taxiway {
+            return SurfacePosition::Taxiway(Some(taxiway.designator.clone()));
+        }
+
+        let on_unnamed_taxiway = self
+            .taxiway_elements
+            .iter()
+            .any(|element| element.taxiway_id.is_none() && element.geometry.intersects(&location));
+
+        if on_unnamed_taxiway {
+            return SurfacePosition::Taxiway(None);
+        }
+
+        let stand_areas: Vec<_> = self
+            .parking_stand_areas
+            .iter()
+            .filter(|area| area.geometry.intersects(&location))
+            .collect();
+
+        if let Some(stand_id) = stand_areas.iter().find_map(|area| area.stand_id.clone()) {
+            return SurfacePosition::Stand(stand_id);
+        }
+
+        // Stand areas without a stand id still place the aircraft on their apron
+        let apron_ids = self
+            .apron_elements
+            .iter()
+            .filter(|apron| apron.geometry.intersects(&location))
+            .map(|apron| &apron.apron_id)
+            .chain(stand_areas.iter().map(|area| &area.apron_id));
+
+        let mut on_apron = false;
+
+        for apron_id in apron_ids {
+            if apron_id.is_some() {
+                return SurfacePosition::Apron(apron_id.clone());
+            }
+
+            on_apron = true;
+        }
+
+        match on_apron {
+            true => SurfacePosition::Apron(None),
+            false => SurfacePosition::OffPavement,
+        }
+    }
+}
@@ -0,0 +1,779 @@
+use geo::{
+    algorithm::line_intersection::line_intersection,
+    BooleanOps,
+    Coord,
+    Line,
+    LineIntersection,
+    LineString,
+    MultiLineString,
+    Rect,
+};
+use rstar::{primitives::GeomWithData, RTree, RTreeObject, AABB};
+
+use crate::{
+    enums::{Direction, GroundSurfaceType, Status},
+    geometry::{turn_radius, LocalProjection},
+    layer::Layer,
+    output_types::{AnyFeature, RunwayId},
+    spatial_index::SpatialIndex,
+    Airport,
+};
+
+/// The distance in metres within which line ends are joined to each other, or to the lines they end on, by default
+pub const DEFAULT_SNAP_TOLERANCE: f64 = 1.0;
+
+/// The distance in metres within which the stop point of a stand is linked to the network
+const STAND_LINK_DISTANCE: f64 = 50.0;
+
+/// The distance in metres between the points along an edge the width of the pavement is measured at
+const PAVEMENT_SAMPLE_SPACING: f64 = 25.0;
+
+/// The furthest distance in metres either side of an edge the pavement is measured to
+const MAX_PAVEMENT_HALF_WIDTH: f64 = 75.0;
+
+/// The gap in metres across which neighbouring pieces of pavement are taken to be continuous
+const PAVEMENT_JOIN_TOLERANCE: f64 = 0.05;
+
+/// The layers whose elements make up the pavement around an edge. Runway elements are included so that the width of
+/// runway exits is not cut short where they join the runway.
+const PAVEMENT_LAYERS: [Layer; 3] = [Layer::TaxiwayElement, Layer::ApronElement, Layer::RunwayElement];
+
+pub type NodeId = usize;
+pub type EdgeId = usize;
+
+/// A point where lines of the taxi network end, meet or cross
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub location: Coord,
+    /// The holding positions which cross the network at this node
+    pub holding_position_ids: Vec<u64>,
+    /// The stands whose stop point is at this node
+    pub stand_ids: Vec<String>,
+}
+
+/// The kind of line an edge was cut from
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    Taxiway,
+    RunwayExit,
+    Stand,
+    Runway,
+}
+
+/// A stretch of line between two nodes of the taxi network
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub kind: EdgeKind,
+    /// The id of the guidance line, exit line or painted centerline the edge was cut from, if it came from a feature
+    pub feature_id: Option<u64>,
+    pub taxiway_id: Option<String>,
+    pub stand_id: Option<String>,
+    pub runway_id: Option<RunwayId>,
+    /// The direction the edge may be travelled in, relative to its geometry
+    pub direction: Direction,
+    pub status: Status,
+    /// The surface of the pavement under the middle of the edge, if it lies on a taxiway, stand or apron
+    pub surface_type: Option<GroundSurfaceType>,
+    /// The length of the edge in metres
+    pub length: f64,
+    /// The narrowest width in metres of the pavement across the edge, or None if it is a runway edge or does not lie
+    /// on pavement
+    pub pavement_width: Option<f64>,
+    /// The radius in metres of the tightest curve along the edge, or None if it is straight or too short to be
+    /// measured
+    pub curve_radius: Option<f64>,
+    /// The geometry of the edge, running from `from` to `to`
+    pub geometry: LineString,
+}
+
+impl Edge {
+    /// The node at the other end of the edge from `node`
+    pub fn other(&self, node: NodeId) -> NodeId {
+        match node == self.from {
+            true => self.to,
+            false => self.from,
+        }
+    }
+
+    /// Whether the edge may be travelled starting from `node`, honouring its direction
+    pub fn traversable_from(&self, node: NodeId) -> bool {
+        match self.direction {
+            Direction::StartToEndpoint => node == self.from,
+            Direction::EndToStartpoint => node == self.to,
+            _ => true,
+        }
+    }
+}
+
+/// The attributes a line passes on to every edge cut from it
+#[derive(Clone)]
+struct LineAttributes {
+    kind: EdgeKind,
+    feature_id: Option<u64>,
+    taxiway_id: Option<String>,
+    stand_id: Option<String>,
+    runway_id: Option<RunwayId>,
+    direction: Direction,
+    status: Status,
+}
+
+/// A line of the network in metres, along with the points it is to be cut at
+struct NetworkLine {
+    coords: Vec<Coord>,
+    attributes: LineAttributes,
+    /// The segment each cut lies on, its position along the segment from 0 to 1 and the point itself
+    cuts: Vec<(usize, f64, Coord)>,
+}
+
+/// A segment of a network line, as stored in the R-tree used to find lines which meet
+struct Segment {
+    line: usize,
+    index: usize,
+    envelope: AABB<[f64; 2]>,
+}
+
+impl RTreeObject for Segment {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope { self.envelope }
+}
+
+/// The envelope of a segment, grown by a distance on every side
+fn segment_envelope(line: Line, margin: f64) -> AABB<[f64; 2]> {
+    AABB::from_corners(
+        [
+            line.start.x.min(line.end.x) - margin,
+            line.start.y.min(line.end.y) - margin,
+        ],
+        [
+            line.start.x.max(line.end.x) + margin,
+            line.start.y.max(line.end.y) + margin,
+        ],
+    )
+}
+
+/// The position of the point closest to `point` along a segment, from 0 at its start to 1 at its end
+fn position_along(line: Line, point: Coord) -> f64 {
+    let delta = line.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+
+    if length_2 == 0.0 {
+        return 0.0;
+    }
+
+    (((point.x - line.start.x) * delta.x + (point.y - line.start.y) * delta.y) / length_2).clamp(0.0, 1.0)
+}
+
+fn distance(a: Coord, b: Coord) -> f64 { (a.x - b.x).hypot(a.y - b.y) }
+
+fn line_length(coords: &[Coord]) -> f64 { coords.windows(2).map(|w| distance(w[0], w[1])).sum() }
+
+/// The point a distance along a line, along with the unit direction of the line there
+fn point_along(coords: &[Coord], mut remaining: f64) -> Option<(Coord, Coord)> {
+    let mut last = None;
+
+    for w in coords.windows(2) {
+        let length = distance(w[0], w[1]);
+
+        if length == 0.0 {
+            continue;
+        }
+
+        let direction = (w[1] - w[0]) / length;
+
+        if length >= remaining {
+            return Some((w[0] + direction * remaining, direction));
+        }
+
+        remaining -= length;
+        last = Some((w[1], direction));
+    }
+
+    last
+}
+
+/// The point halfway along a line
+fn midpoint(coords: &[Coord]) -> Coord {
+    point_along(coords, line_length(coords) / 2.0).map_or(coords[0], |(point, _)| point)
+}
+
+/// The width in metres of the continuous pavement across a line at a point in metres, or None if the point is not on
+/// pavement
+fn cross_section(point: Coord, direction: Coord, projection: &LocalProjection, index: &SpatialIndex) -> Option<f64> {
+    let normal = Coord {
+        x: -direction.y,
+        y: direction.x,
+    };
+
+    // The projection is linear, so the section can be cut in longitude and latitude and measured in metres
+    let section = Line::new(
+        projection.unproject(point - normal * MAX_PAVEMENT_HALF_WIDTH),
+        projection.unproject(point + normal * MAX_PAVEMENT_HALF_WIDTH),
+    );
+    let sections = MultiLineString::new(vec![section.into()]);
+
+    let across = |coord: Coord| {
+        let offset = projection.project(coord) - point;
+        offset.x * normal.x + offset.y * normal.y
+    };
+
+    let mut spans: Vec<(f64, f64)> = index
+        .in_rect(Rect::new(section.start, section.end), &PAVEMENT_LAYERS)
+        .into_iter()
+        .filter_map(|feature| match feature {
+            AnyFeature::TaxiwayElement(element) => Some(&element.geometry),
+            AnyFeature::ApronElement(element) => Some(&element.geometry),
+            AnyFeature::RunwayElement(element) => Some(&element.geometry),
+            _ => None,
+        })
+        .flat_map(|geometry| geometry.clip(&sections, false))
+        .filter_map(|piece| {
+            let (start, end) = (across(*piece.0.first()?), across(*piece.0.last()?));
+            Some((start.min(end), start.max(end)))
+        })
+        .collect();
+
+    spans.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let mut merged: Vec<(f64, f64)> = Vec::new();
+
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end + PAVEMENT_JOIN_TOLERANCE => *last_end = last_end.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+        .into_iter()
+        .find(|&(start, end)| start <= PAVEMENT_JOIN_TOLERANCE && end >= -PAVEMENT_JOIN_TOLERANCE)
+        .map(|(start, end)| end - start)
+}
+
+/// The narrowest width in metres of the pavement across a line in metres, measured at intervals along it, or None if
+/// the line does not lie on pavement
+fn pavement_width(coords: &[Coord], projection: &LocalProjection, index: &SpatialIndex) -> Option<f64> {
+    let length = line_length(coords);
+    let samples = ((length / PAVEMENT_SAMPLE_SPACING).ceil() as usize).max(1);
+
+    (0..samples)
+        .filter_map(|sample| {
+            let (point, direction) = point_along(coords, length * (sample as f64 + 0.5) / samples as f64)?;
+            cross_section(point, direction, projection, index)
+        })
+        .min_by(f64::total_cmp)
+}
+
+/// Drops the vertices of a line in metres which lie within `tolerance` of the vertex kept before them, keeping both
+/// ends. The short segments left where lines are cut and snapped are merged into their neighbours this way, so they do
+/// not read as tight turns.
+pub(crate) fn drop_short_segments(coords: &[Coord], tolerance: f64) -> Vec<Coord> {
+    let Some((&last, rest)) = coords.split_last() else {
+        return Vec::new();
+    };
+
+    let mut kept: Vec<Coord> = Vec::with_capacity(coords.len());
+
+    for &coord in rest {
+        if kept
+            .last()
+            .is_none_or(|&previous| distance(previous, coord) >= tolerance)
+        {
+            kept.push(coord);
+        }
+    }
+
+    while kept.len() > 1 && distance(kept[kept.len() - 1], last) < tolerance {
+        kept.pop();
+    }
+
+    kept.push(last);
+    kept
+}
+
+fn project(projection: &LocalProjection, line: &LineString) -> Vec<Coord> {
+    line.coords().map(|&coord| projection.project(coord)).collect()
+}
+
+/// Collects the lines which make up the taxi network of an airport, in metres
+fn network_lines(airport: &Airport, projection: &LocalProjection) -> Vec<NetworkLine> {
+    let mut lines = Vec::new();
+
+    let mut add = |coords: Vec<Coord>, attributes: &LineAttributes| {
+        if coords.len() > 1 {
+            lines.push(NetworkLine {
+                coords,
+                attributes: attributes.clone(),
+                cuts: Vec::new(),
+            });
+        }
+    };
+
+    for guidance_line in &airport.taxiway_guidance_lines {
+        let attributes = LineAttributes {
+            kind: EdgeKind::Taxiway,
+            feature_id: Some(guidance_line.id),
+            taxiway_id: guidance_line.taxiway_id.clone(),
+            stand_id: None,
+            runway_id: None,
+            direction: guidance_line.direction,
+            status: guidance_line.status,
+        };
+
+        guidance_line
+            .geometry
+            .iter()
+            .for_each(|line| add(project(projection, line), &attributes));
+    }
+
+    for exit_line in &airport.runway_exit_lines {
+        let attributes = LineAttributes {
+            kind: EdgeKind::RunwayExit,
+            feature_id: Some(exit_line.id),
+            taxiway_id: exit_line.taxiway_id.clone(),
+            stand_id: None,
+            runway_id: None,
+            direction: exit_line.direction,
+            status: exit_line.status,
+        };
+
+        exit_line
+            .geometry
+            .iter()
+            .for_each(|line| add(project(projection, line), &attributes));
+    }
+
+    for guidance_line in &airport.stand_guidance_lines {
+        let attributes = LineAttributes {
+            kind: EdgeKind::Stand,
+            feature_id: Some(guidance_line.id),
+            taxiway_id: None,
+            stand_id: guidance_line.stand_id.clone(),
+            runway_id: None,
+            direction: guidance_line.direction,
+            status: guidance_line.status,
+        };
+
+        guidance_line
+            .geometry
+            .iter()
+            .for_each(|line| add(project(projection, line), &attributes));
+    }
+
+    // Runways are linked in through their painted centerlines, or the centerline of their pavement without them
+    for runway in airport.runways() {
+        let mut attributes = LineAttributes {
+            kind: EdgeKind::Runway,
+            feature_id: None,
+            taxiway_id: None,
+            stand_id: None,
+            runway_id: Some(runway.runway_id.clone()),
+            direction: Direction::Bidirectional,
+            status: Status::Open,
+        };
+
+        let mut painted = runway.painted_centerlines(airport).peekable();
+
+        if painted.peek().is_some() {
+            for centerline in painted {
+                attributes.feature_id = Some(centerline.id);
+                centerline
+                    .geometry
+                    .iter()
+                    .for_each(|line| add(project(projection, line), &attributes));
+            }
+        } else if let Some(centerline) = runway.centerline {
+            add(
+                vec![projection.project(centerline.start), projection.project(centerline.end)],
+                &attributes,
+            );
+        }
+    }
+
+    lines
+}
+
+/// Finds the points each line is to be cut at, returning the points where holding positions cross the network
+fn cut_lines(
+    lines: &mut [NetworkLine], airport: &Airport, projection: &LocalProjection, snap_tolerance: f64,
+) -> Vec<(u64, Coord)> {
+    let segments = RTree::bulk_load(
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(line, network_line)| {
+                network_line
+                    .coords
+                    .windows(2)
+                    .enumerate()
+                    .map(move |(index, w)| Segment {
+                        line,
+                        index,
+                        envelope: segment_envelope(Line::new(w[0], w[1]), snap_tolerance),
+                    })
+            })
+            .collect(),
+    );
+
+    let segment = |lines: &[NetworkLine], line: usize, index: usize| {
+        Line::new(lines[line].coords[index], lines[line].coords[index + 1])
+    };
+
+    // Cut lines where they cross each other
+    let mut cuts = Vec::new();
+
+    for (line, network_line) in lines.iter().enumerate() {
+        for (index, w) in network_line.coords.windows(2).enumerate() {
+            let a = Line::new(w[0], w[1]);
+
+            for other in segments.locate_in_envelope_intersecting(&segment_envelope(a, 0.0)) {
+                if other.line <= line {
+                    continue;
+                }
+
+                let b = segment(lines, other.line, other.index);
+
+                if let Some(LineIntersection::SinglePoint { intersection, .. }) = line_intersection(a, b) {
+                    cuts.push((line, index, position_along(a, intersection), intersection));
+                    cuts.push((other.line, other.index, position_along(b, intersection), intersection));
+                }
+            }
+        }
+    }
+
+    // Cut lines where another line ends on them
+    for (line, network_line) in lines.iter().enumerate() {
+        for end in [
+            network_line.coords[0],
+            network_line.coords[network_line.coords.len() - 1],
+        ] {
+            let envelope = AABB::from_corners(
+                [end.x - snap_tolerance, end.y - snap_tolerance],
+                [end.x + snap_tolerance, end.y + snap_tolerance],
+            );
+
+            for other in segments.locate_in_envelope_intersecting(&envelope) {
+                if other.line == line {
+                    continue;
+                }
+
+                let b = segment(lines, other.line, other.index);
+                let position = position_along(b, end);
+                let closest = b.start + b.delta() * position;
+
+                if distance(closest, end) <= snap_tolerance {
+                    cuts.push((other.line, other.index, position, closest));
+                }
+            }
+        }
+    }
+
+    // Cut lines where holding positions cross them
+    let mut holding_points = Vec::new();
+
+    for position in &airport.taxiway_holding_positions {
+        for line in &position.geometry {
+            for w in project(projection, line).windows(2) {
+                let a = Line::new(w[0], w[1]);
+
+                for other in segments.locate_in_envelope_intersecting(&segment_envelope(a, 0.0)) {
+                    let b = segment(lines, other.line, other.index);
+
+                    if let Some(LineIntersection::SinglePoint { intersection, .. }) = line_intersection(a, b) {
+                        cuts.push((other.line, other.index, position_along(b, intersection), intersection));
+                        holding_points.push((position.id, intersection));
+                    }
+                }
+            }
+        }
+    }
+
+    for (line, index, position, point) in cuts {
+        lines[line].cuts.push((index, position, point));
+    }
+
+    holding_points
+}
+
+/// The network of taxiway guidance lines, runway exit lines, stand guidance lines and runway centerlines of an
+/// airport.
+///
+/// Lines are cut into edges wherever they end, cross each other, end on another line or are crossed by a holding
+/// position. Line ends within the snap tolerance of each other are joined into a single node.
+pub struct TaxiGraph {
+    projection: LocalProjection,
+    snap_tolerance: f64,
+    nodes: Vec<Node>,
+    /// The location of each node in metres
+    points: Vec<Coord>,
+    /// The nodes indexed by their location in metres
+    positions: RTree<GeomWithData<[f64; 2], NodeId>>,
+    edges: Vec<Edge>,
+    /// The edges which meet at each node
+    incident: Vec<Vec<EdgeId>>,
+}
+
+impl TaxiGraph {
+    /// Builds the taxi network of an airport, joining line ends within `snap_tolerance` metres of each other
+    pub fn new(airport: &Airport, snap_tolerance: f64) -> Self {
+        let projection = LocalProjection::new(airport.aerodrome_reference_point.location);
+        let mut lines = network_lines(airport, &projection);
+        let holding_points = cut_lines(&mut lines, airport, &projection, snap_tolerance);
+
+        let mut graph = Self {
+            projection,
+            snap_tolerance,
+            nodes: Vec::new(),
+            points: Vec::new(),
+            positions: RTree::new(),
+            edges: Vec::new(),
+            incident: Vec::new(),
+        };
+
+        let index = airport.spatial_index();
+
+        for mut network_line in lines {
+            network_line
+                .cuts
+                .sort_by(|(a, a_position, _), (b, b_position, _)| a.cmp(b).then(a_position.total_cmp(b_position)));
+
+            let mut piece = vec![network_line.coords[0]];
+            let mut cuts = network_line.cuts.iter().peekable();
+
+            for (index_along, &end) in network_line.coords.iter().enumerate().skip(1) {
+                while let Some(&(_, _, point)) = cuts.next_if(|(segment, ..)| *segment == index_along - 1) {
+                    piece.push(point);
+                    graph.add_edge(
+                        std::mem::replace(&mut piece, vec![point]),
+                        &network_line.attributes,
+                        &index,
+                    );
+                }
+
+                piece.push(end);
+            }
+
+            graph.add_edge(piece, &network_line.attributes, &index);
+        }
+
+        for (id, point) in holding_points {
+            let node = graph.node_at(point);
+
+            if !graph.nodes[node].holding_position_ids.contains(&id) {
+                graph.nodes[node].holding_position_ids.push(id);
+            }
+        }
+
+        for stand in airport.stands() {
+            let Some(stop_point) = stand
+                .lead_in_line
+                .as_ref()
+                .and_then(|line| line.0.last().copied())
+                .or(stand.stop_point)
+            else {
+                continue;
+            };
+
+            if let Some(node) = graph.nearest_node_within(stop_point, STAND_LINK_DISTANCE) {
+                graph.nodes[node].stand_ids.push(stand.stand_id.clone());
+            }
+        }
+
+        graph
+    }
+
+    /// Finds the node at a point in metres, adding one if there is none within the snap tolerance
+    fn node_at(&mut self, point: Coord) -> NodeId {
+        let nearest = self
+            .positions
+            .nearest_neighbor(&[point.x, point.y])
+            .filter(|node| distance(Coord::from(*node.geom()), point) <= self.snap_tolerance);
+
+        if let Some(node) = nearest {
+            return node.data;
+        }
+
+        let node = self.nodes.len();
+
+        self.nodes.push(Node {
+            location: self.projection.unproject(point),
+            holding_position_ids: Vec::new(),
+            stand_ids: Vec::new(),
+        });
+        self.points.push(point);
+        self.positions.insert(GeomWithData::new([point.x, point.y], node));
+        self.incident.push(Vec::new());
+
+        node
+    }
+
+    /// Adds an edge along a piece of line in metres, joining its ends to the nodes at them
+    fn add_edge(&mut self, mut coords: Vec<Coord>, attributes: &LineAttributes, index: &SpatialIndex) {
+        coords.dedup_by(|a, b| distance(*a, *b) < f64::EPSILON);
+
+        let (Some(&first), Some(&last)) = (coords.first(), coords.last()) else {
+            return;
+        };
+
+        let from = self.node_at(first);
+        let to = self.node_at(last);
+
+        // Draw the ends in to the nodes they were joined to
+        let len = coords.len();
+        coords[0] = self.points[from];
+        coords[len - 1] = self.points[to];
+
+        let length = line_length(&coords);
+
+        if from == to && length <= self.snap_tolerance {
+            return;
+        }
+
+        let surface_type = match attributes.kind {
+            EdgeKind::Runway => None,
+            _ => {
+                let midpoint = self.projection.unproject(midpoint(&coords));
+                let features = index.at_point(
+                    midpoint,
+                    &[Layer::TaxiwayElement, Layer::ParkingStandArea, Layer::ApronElement],
+                );
+
+                [Layer::TaxiwayElement, Layer::ParkingStandArea, Layer::ApronElement]
+                    .iter()
+                    .find_map(|&layer| {
+                        features.iter().find_map(|feature| match feature {
+                            AnyFeature::TaxiwayElement(element) if layer == Layer::TaxiwayElement => {
+                                Some(element.surface_type)
+                            },
+                            AnyFeature::ParkingStandArea(area) if layer == Layer::ParkingStandArea => {
+                                Some(area.surface_type)
+                            },
+                            AnyFeature::ApronElement(apron) if layer == Layer::ApronElement => Some(apron.surface_type),
+                            _ => None,
+                        })
+                    })
+            },
+        };
+
+        let pavement_width = match attributes.kind {
+            EdgeKind::Runway => None,
+            _ => pavement_width(&coords, &self.projection, index),
+        };
+
+        let curve_radius = drop_short_segments(&coords, self.snap_tolerance)
+            .windows(3)
+            .filter_map(|w| turn_radius(w[0], w[1], w[2]))
+            .min_by(f64::total_cmp);
+
+        let edge = self.edges.len();
+
+        self.edges.push(Edge {
+            from,
+            to,
+            kind: attributes.kind,
+            feature_id: attributes.feature_id,
+            taxiway_id: attributes.taxiway_id.clone(),
+            stand_id: attributes.stand_id.clone(),
+            runway_id: attributes.runway_id.clone(),
+            direction: attributes.direction,
+            status: attributes.status,
+            surface_type,
+            length,
+            pavement_width,
+            curve_radius,
+            geometry: coords
+                .into_iter()
+                .map(|coord| self.projection.unproject(coord))
+                .collect(),
+        });
+
+        self.incident[from].push(edge);
+
+        if to != from {
+            self.incident[to].push(edge);
+        }
+    }
+
+    /// The nearest node to a point in metres, if there is one within `max_distance` metres
+    fn nearest_node_within(&self, location: Coord, max_distance: f64) -> Option<NodeId> {
+        let point = self.projection.project(location);
+
+        self.positions
+            .nearest_neighbor(&[point.x, point.y])
+            .filter(|node| distance(Coord::from(*node.geom()), point) <= max_distance)
+            .map(|node| node.data)
+    }
+
+    pub fn nodes(&self) -> &[Node] { &self.nodes }
+
+    pub fn edges(&self) -> &[Edge] { &self.edges }
+
+    pub fn node(&self, node: NodeId) -> &Node { &self.nodes[node] }
+
+    pub fn edge(&self, edge: EdgeId) -> &Edge { &self.edges[edge] }
+
+    pub(crate) fn projection(&self) -> &LocalProjection { &self.projection }
+
+    pub(crate) fn snap_tolerance(&self) -> f64 { self.snap_tolerance }
+
+    /// The location of a node in metres
+    pub(crate) fn point(&self, node: NodeId) -> Coord { self.points[node] }
+
+    /// The edges which meet at a node, whether or not they may be travelled from it
+    pub fn edges_at(&self, node: NodeId) -> impl Iterator<Item = (EdgeId, &Edge)> {
+        self.incident[node].iter().map(|&edge| (edge, &self.edges[edge]))
+    }
+
+    /// The nearest node to a location
+    pub fn nearest_node(&self, location: Coord) -> Option<NodeId> { self.nearest_node_within(location, f64::INFINITY) }
+
+    /// The node a stand is linked to the network at
+    pub fn stand_node(&self, stand_id: &str) -> Option<NodeId> {
+        self.nodes
+            .iter()
+            .position(|node| node.stand_ids.iter().any(|id| id == stand_id))
+    }
+
+    /// The nodes within `max_distance` metres along the network of any of the start nodes, in either direction along
+    /// every edge, together with their distances. Nodes are given nearest first, starting with the start nodes.
+    pub(crate) fn nodes_within(&self, starts: &[NodeId], max_distance: f64) -> Vec<(NodeId, f64)> {
+        let mut frontier: Vec<(NodeId, f64)> = starts.iter().map(|&node| (node, 0.0)).collect();
+        let mut reached: Vec<(NodeId, f64)> = Vec::new();
+
+        while let Some(index) = frontier
+            .iter()
+            .enumerate()
+            .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+            .map(|(index, _)| index)
+        {
+            let (node, node_distance) = frontier.swap_remove(index);
+
+            if reached.iter().any(|&(reached, _)| reached == node) {
+                continue;
+            }
+
+            reached.push((node, node_distance));
+
+            for (_, edge) in self.edges_at(node) {
+                let next_distance = node_distance + edge.length;
+
+                if next_distance <= max_distance {
+                    frontier.push((edge.other(node), next_distance));
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// The nodes where a holding position crosses the network
+    pub fn holding_position_nodes(&self, holding_position_id: u64) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, node)| node.holding_position_ids.contains(&holding_position_id))
+            .map(|(index, _)| index)
+    }
+}
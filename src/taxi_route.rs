@@ -0,0 +1,652 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    fmt,
+};
+
+use geo::{Coord, LineString};
+
+use crate::{
+    enums::Status,
+    error::RouteError,
+    geometry::turn_radius,
+    taxi_graph::{drop_short_segments, EdgeId, EdgeKind, NodeId, TaxiGraph},
+};
+
+/// A place a taxi route can start or end at
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteLocation {
+    /// The node a stand is linked to the network at, by its stand id
+    Stand(String),
+    /// The nodes where a holding position crosses the network, by its feature id
+    HoldingPosition(u64),
+    /// The runway end of a runway exit line, by its feature id
+    RunwayExit(u64),
+    Node(NodeId),
+    /// An arbitrary location, joined to the closest point of the nearest open edge
+    Point(Coord),
+}
+
+impl fmt::Display for RouteLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteLocation::Stand(stand_id) => write!(f, "stand {stand_id}"),
+            RouteLocation::HoldingPosition(id) => write!(f, "holding position {id}"),
+            RouteLocation::RunwayExit(id) => write!(f, "runway exit {id}"),
+            RouteLocation::Node(node) => write!(f, "node {node}"),
+            RouteLocation::Point(point) => write!(f, "point ({}, {})", point.x, point.y),
+        }
+    }
+}
+
+/// The shortest route between two locations on a [TaxiGraph]
+#[derive(Debug, Clone)]
+pub struct TaxiRoute {
+    /// The edges travelled along, in order. The first and last are only travelled in part when the route starts or
+    /// ends between nodes, and an edge listed twice in a row is travelled there and back.
+    pub edges: Vec<EdgeId>,
+    /// The nodes passed through, in order
+    pub nodes: Vec<NodeId>,
+    pub geometry: LineString,
+    /// The length of the route in metres
+    pub length: f64,
+    /// The taxiways travelled along, in order, with consecutive repeats removed
+    pub taxiway_ids: Vec<String>,
+}
+
+/// The dimensions of an aircraft which limit where it can taxi, in metres
+#[derive(Debug, Clone, PartialEq)]
+pub struct AircraftProfile {
+    pub wingspan: f64,
+    /// The distance between the outside edges of the outermost main gear wheels
+    pub gear_span: f64,
+    /// The distance from the nose gear to the middle of the main gear
+    pub wheelbase: f64,
+    /// The radius of the tightest turn the aircraft can make, measured at the nose gear
+    pub turn_radius: f64,
+}
+
+impl AircraftProfile {
+    /// The clearance the main gear must keep from the edge of the pavement, as required by ICAO for the aerodrome code
+    /// letter the wingspan falls in
+    fn edge_clearance(&self) -> f64 {
+        match self.wingspan {
+            wingspan if wingspan < 15.0 => 1.5,
+            wingspan if wingspan < 24.0 => 2.25,
+            wingspan if wingspan < 36.0 => 3.0,
+            _ => 4.0,
+        }
+    }
+
+    /// The width of pavement the aircraft needs to follow a line, given the radius of the tightest curve along it
+    pub fn required_width(&self, curve_radius: Option<f64>) -> f64 {
+        // Around a curve the main gear tracks inside the nose gear, by more the longer the wheelbase
+        let offset = curve_radius.map_or(0.0, |radius| {
+            radius - (radius.powi(2) - self.wheelbase.powi(2)).max(0.0).sqrt()
+        });
+
+        self.gear_span + 2.0 * (self.edge_clearance() + offset)
+    }
+}
+
+/// A part of the taxi network an aircraft is too large to use
+#[derive(Debug, Clone, PartialEq)]
+pub enum Limitation {
+    /// An edge whose pavement is narrower than the aircraft needs, with both widths in metres
+    Width { edge: EdgeId, width: f64, required: f64 },
+    /// An edge which curves more tightly than the aircraft can turn, with the radius of the curve in metres
+    Curve { edge: EdgeId, radius: f64 },
+    /// A turn at a node from one edge onto another which is tighter than the aircraft can make, with its radius in
+    /// metres
+    Turn {
+        node: NodeId,
+        from: EdgeId,
+        to: EdgeId,
+        radius: f64,
+    },
+}
+
+impl fmt::Display for Limitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limitation::Width { edge, width, required } => {
+                write!(f, "edge {edge} is {width:.1} m wide where {required:.1} m is needed")
+            },
+            Limitation::Curve { edge, radius } => write!(f, "edge {edge} curves with a radius of {radius:.1} m"),
+            Limitation::Turn { node, from, to, radius } => write!(
+                f,
+                "the turn from edge {from} onto edge {to} at node {node} has a radius of {radius:.1} m"
+            ),
+        }
+    }
+}
+
+/// A location snapped part way along an edge
+struct Snap {
+    edge: EdgeId,
+    /// The segment of the edge geometry the location lies on
+    segment: usize,
+    /// The closest point of the edge to the location
+    point: Coord,
+    /// The distance in metres from the start of the edge to the point
+    along: f64,
+}
+
+/// Where a location lies on the network
+enum Located {
+    Nodes(Vec<NodeId>),
+    Edge(Snap),
+}
+
+/// A way between a location and a node of the network, along with the part of an edge it takes to reach the node
+struct Access {
+    node: NodeId,
+    cost: f64,
+    /// The part of an edge between the location and the node, running from the location to the node
+    partial: Option<(EdgeId, Vec<Coord>)>,
+}
+
+/// The cost on top of its length of entering an edge from a node, given the edge it was reached by, or None if the edge
+/// may not be entered
+type Entry<'a> = dyn Fn(Option<EdgeId>, EdgeId, NodeId) -> Option<f64> + 'a;
+
+/// The cost added for each limitation when looking for the route an aircraft is least limited on, far more than the
+/// length of any route across an airport
+const LIMITATION_PENALTY: f64 = 1e7;
+
+/// The distance in metres within which a location snapped onto an edge is taken to lie on the node at its end
+const NODE_TOLERANCE: f64 = 0.01;
+
+/// A node of the search, along with the edge it was reached by
+type Step = (NodeId, Option<EdgeId>);
+
+/// A step waiting to be searched from, ordered so the heap pops the lowest estimate first
+struct Pending {
+    estimate: f64,
+    step: Step,
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Self) -> bool { self.estimate == other.estimate }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering { other.estimate.total_cmp(&self.estimate) }
+}
+
+fn distance(a: Coord, b: Coord) -> f64 { (b.x - a.x).hypot(b.y - a.y) }
+
+impl TaxiGraph {
+    /// Finds the shortest route between two locations.
+    ///
+    /// Edges are only travelled in the direction they allow, and closed edges are never used. Runways are never taxied
+    /// along: a route may cross one at a node or join it through a runway exit, and a location on a runway is only
+    /// joined to the network along the runway as far as the nearest node.
+    pub fn route(&self, from: &RouteLocation, to: &RouteLocation) -> Result<TaxiRoute, RouteError> {
+        self.route_along(from, to, &|_, edge, node| self.is_open(edge, node).then_some(0.0))
+    }
+
+    /// Finds the shortest route between two locations which an aircraft is small enough to follow.
+    ///
+    /// As well as keeping to open edges in their permitted direction, the route avoids pavement too narrow for the
+    /// aircraft and turns tighter than it can make. If only such routes exist, the error gives the first part the
+    /// aircraft cannot negotiate of the route with the fewest such parts.
+    pub fn route_for_aircraft(
+        &self, from: &RouteLocation, to: &RouteLocation, aircraft: &AircraftProfile,
+    ) -> Result<TaxiRoute, RouteError> {
+        let result = self.route_along(from, to, &|previous, edge, node| {
+            (self.is_open(edge, node) && self.limitation(aircraft, previous, edge, node).is_none()).then_some(0.0)
+        });
+
+        if !matches!(result, Err(RouteError::NoRoute)) {
+            return result;
+        }
+
+        // Allow the aircraft anywhere it is limited, at a cost which makes the route with the fewest limitations win
+        let route = self.route_along(from, to, &|previous, edge, node| {
+            self.is_open(edge, node)
+                .then(|| match self.limitation(aircraft, previous, edge, node) {
+                    Some(_) => LIMITATION_PENALTY,
+                    None => 0.0,
+                })
+        })?;
+
+        // Each edge is entered from the node before it, except the first where the route starts part way along it
+        let starts_between_nodes = match route.nodes.first() {
+            Some(&node) => route.geometry.0.first() != Some(&self.node(node).location),
+            None => true,
+        };
+        let entered_from = |index: usize| match starts_between_nodes {
+            true => index.checked_sub(1).and_then(|index| route.nodes.get(index)),
+            false => route.nodes.get(index),
+        };
+
+        let limitation = route.edges.iter().enumerate().find_map(|(index, &edge)| {
+            let previous = index.checked_sub(1).map(|index| route.edges[index]);
+
+            match entered_from(index) {
+                Some(&node) => self.limitation(aircraft, previous, edge, node),
+                None => self.edge_limitation(aircraft, edge),
+            }
+        });
+
+        match limitation {
+            Some(limitation) => Err(RouteError::Limited(limitation)),
+            None => Err(RouteError::NoRoute),
+        }
+    }
+
+    /// Whether an edge is open and may be travelled starting from `node`
+    fn is_open(&self, edge: EdgeId, node: NodeId) -> bool {
+        let edge = self.edge(edge);
+        edge.status != Status::Closed && edge.traversable_from(node)
+    }
+
+    /// What stops an aircraft entering an edge from a node, having reached the node along `previous`
+    fn limitation(
+        &self, aircraft: &AircraftProfile, previous: Option<EdgeId>, edge: EdgeId, node: NodeId,
+    ) -> Option<Limitation> {
+        self.edge_limitation(aircraft, edge)
+            .or_else(|| self.turn_limitation(aircraft, previous?, edge, node))
+    }
+
+    /// What stops an aircraft following an edge, wherever it joins it
+    fn edge_limitation(&self, aircraft: &AircraftProfile, edge: EdgeId) -> Option<Limitation> {
+        let candidate = self.edge(edge);
+
+        if let Some(radius) = candidate.curve_radius.filter(|&radius| radius < aircraft.turn_radius) {
+            return Some(Limitation::Curve { edge, radius });
+        }
+
+        let width = candidate.pavement_width?;
+        let required = aircraft.required_width(candidate.curve_radius);
+
+        (width < required).then_some(Limitation::Width { edge, width, required })
+    }
+
+    /// What stops an aircraft turning from `previous` onto an edge at a node. The turn is measured to the first vertex
+    /// along each edge more than the snap tolerance from the node, so a short stub left by snapping is not mistaken for
+    /// the direction of the edge.
+    fn turn_limitation(
+        &self, aircraft: &AircraftProfile, previous: EdgeId, edge: EdgeId, node: NodeId,
+    ) -> Option<Limitation> {
+        // The vertex next to the node along an edge, at whichever end of it the node lies
+        let neighbour = |edge: EdgeId| {
+            let edge = self.edge(edge);
+            let mut coords: Vec<_> = edge
+                .geometry
+                .coords()
+                .map(|&coord| self.projection().project(coord))
+                .collect();
+
+            if edge.from != node {
+                coords.reverse();
+            }
+
+            drop_short_segments(&coords, self.snap_tolerance()).get(1).copied()
+        };
+
+        let radius = turn_radius(neighbour(previous)?, self.point(node), neighbour(edge)?)?;
+
+        (radius < aircraft.turn_radius).then_some(Limitation::Turn {
+            node,
+            from: previous,
+            to: edge,
+            radius,
+        })
+    }
+
+    /// Finds the cheapest route between two locations, using only the edges `entry` allows to be entered from a node
+    /// and adding the cost it gives for entering them to their length
+    fn route_along(&self, from: &RouteLocation, to: &RouteLocation, entry: &Entry) -> Result<TaxiRoute, RouteError> {
+        let start = self.locate(from)?;
+        let end = self.locate(to)?;
+
+        // Two locations on the same edge may be joined without passing through a node
+        let direct = match (&start, &end) {
+            (Located::Edge(a), Located::Edge(b)) if a.edge == b.edge => self.direct_route(a, b, entry),
+            _ => None,
+        };
+
+        let sources = self.accesses(&start, true, entry);
+        let targets = self.accesses(&end, false, entry);
+
+        match (direct, self.search(&sources, &targets, entry)) {
+            (Some(direct), Some(route)) if route.length < direct.length => Ok(route),
+            (Some(direct), _) => Ok(direct),
+            (None, Some(route)) => Ok(route),
+            (None, None) => Err(RouteError::NoRoute),
+        }
+    }
+
+    /// Finds where a location lies on the network
+    fn locate(&self, location: &RouteLocation) -> Result<Located, RouteError> {
+        let nodes: Vec<_> = match location {
+            RouteLocation::Stand(stand_id) => self.stand_node(stand_id).into_iter().collect(),
+            RouteLocation::HoldingPosition(id) => self.holding_position_nodes(*id).collect(),
+            RouteLocation::RunwayExit(id) => self.runway_exit_nodes(*id),
+            RouteLocation::Node(node) => (*node < self.nodes().len()).then_some(*node).into_iter().collect(),
+            RouteLocation::Point(point) => match self.snap(*point) {
+                Some(snap) => {
+                    let edge = self.edge(snap.edge);
+
+                    // A location at the end of an edge lies on its node, and travels none of the edge
+                    return Ok(match snap.along {
+                        along if along <= NODE_TOLERANCE => Located::Nodes(vec![edge.from]),
+                        along if along >= edge.length - NODE_TOLERANCE => Located::Nodes(vec![edge.to]),
+                        _ => Located::Edge(snap),
+                    });
+                },
+                None => Vec::new(),
+            },
+        };
+
+        match nodes.is_empty() {
+            true => Err(RouteError::UnknownLocation(location.clone())),
+            false => Ok(Located::Nodes(nodes)),
+        }
+    }
+
+    /// The nodes where a runway exit line meets a runway, or the ends of the line if it meets none
+    fn runway_exit_nodes(&self, exit_line_id: u64) -> Vec<NodeId> {
+        let on_exit = |node: &NodeId| {
+            self.edges_at(*node)
+                .any(|(_, edge)| edge.kind == EdgeKind::RunwayExit && edge.feature_id == Some(exit_line_id))
+        };
+        let on_runway = |node: &NodeId| self.edges_at(*node).any(|(_, edge)| edge.kind == EdgeKind::Runway);
+
+        let ends: Vec<_> = (0..self.nodes().len()).filter(on_exit).collect();
+        let runway_ends: Vec<_> = ends.iter().copied().filter(on_runway).collect();
+
+        match runway_ends.is_empty() {
+            true => ends,
+            false => runway_ends,
+        }
+    }
+
+    /// Finds the closest point to a location on an open edge. Whether the edge may be travelled the way the route needs
+    /// is left to the search, so that a location is never moved onto a more distant edge.
+    fn snap(&self, location: Coord) -> Option<Snap> {
+        let point = self.projection().project(location);
+
+        let (edge, segment, closest, _) = self
+            .edges()
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.status != Status::Closed)
+            .flat_map(|(edge, candidate)| {
+                candidate.geometry.lines().enumerate().map(move |(segment, line)| {
+                    let start = self.projection().project(line.start);
+                    let delta = self.projection().project(line.end) - start;
+                    let length_2 = delta.x * delta.x + delta.y * delta.y;
+
+                    let position = match length_2 > 0.0 {
+                        true => {
+                            (((point - start).x * delta.x + (point - start).y * delta.y) / length_2).clamp(0.0, 1.0)
+                        },
+                        false => 0.0,
+                    };
+                    let closest = start + delta * position;
+
+                    (edge, segment, closest, distance(closest, point))
+                })
+            })
+            .min_by(|(.., a), (.., b)| a.total_cmp(b))?;
+
+        let coords = &self.edge(edge).geometry.0;
+        let along = coords[..=segment]
+            .windows(2)
+            .map(|w| distance(self.projection().project(w[0]), self.projection().project(w[1])))
+            .sum::<f64>()
+            + distance(self.projection().project(coords[segment]), closest);
+
+        Some(Snap {
+            edge,
+            segment,
+            point: self.projection().unproject(closest),
+            along,
+        })
+    }
+
+    /// The ways onto the network from a location when `leaving` it, or off the network into it otherwise
+    fn accesses(&self, located: &Located, leaving: bool, entry: &Entry) -> Vec<Access> {
+        let snap = match located {
+            Located::Nodes(nodes) => {
+                return nodes
+                    .iter()
+                    .map(|&node| Access {
+                        node,
+                        cost: 0.0,
+                        partial: None,
+                    })
+                    .collect()
+            },
+            Located::Edge(snap) => snap,
+        };
+
+        let edge = self.edge(snap.edge);
+        let coords = &edge.geometry.0;
+
+        let mut to_start = vec![snap.point];
+        to_start.extend(coords[..=snap.segment].iter().rev());
+
+        let mut to_end = vec![snap.point];
+        to_end.extend(&coords[snap.segment + 1..]);
+
+        // Leaving towards the end of the edge travels it forwards, as does arriving from its start
+        let forwards = entry(None, snap.edge, edge.from);
+        let backwards = entry(None, snap.edge, edge.to);
+
+        [
+            (
+                edge.from,
+                to_start,
+                snap.along,
+                if leaving { backwards } else { forwards },
+            ),
+            (
+                edge.to,
+                to_end,
+                edge.length - snap.along,
+                if leaving { forwards } else { backwards },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(node, geometry, cost, extra)| {
+            let extra = extra?;
+
+            // The cost of entering the edge on the way into a location is left to the search, which knows the edge it
+            // was reached by
+            Some(Access {
+                node,
+                cost: cost.max(0.0) + if leaving { extra } else { 0.0 },
+                partial: Some((snap.edge, geometry)),
+            })
+        })
+        .collect()
+    }
+
+    /// The route between two locations on the same edge, if the edge may be travelled from one to the other
+    fn direct_route(&self, start: &Snap, end: &Snap, entry: &Entry) -> Option<TaxiRoute> {
+        let edge = self.edge(start.edge);
+        let coords = &edge.geometry.0;
+        let forwards = start.along <= end.along;
+
+        let extra = entry(None, start.edge, if forwards { edge.from } else { edge.to })?;
+
+        let mut geometry = vec![start.point];
+
+        match forwards {
+            true => geometry.extend(&coords[start.segment + 1..=end.segment.max(start.segment)]),
+            false => geometry.extend(coords[end.segment + 1..=start.segment.max(end.segment)].iter().rev()),
+        }
+
+        geometry.push(end.point);
+
+        Some(self.assemble(
+            vec![start.edge],
+            Vec::new(),
+            geometry,
+            (end.along - start.along).abs() + extra,
+        ))
+    }
+
+    /// Searches the network with A* for the cheapest path from any source to any target.
+    ///
+    /// Each step of the search is a node along with the edge it was reached by, so that `entry` can judge the turn
+    /// from one edge onto the next.
+    fn search(&self, sources: &[Access], targets: &[Access], entry: &Entry) -> Option<TaxiRoute> {
+        let mut cost: HashMap<Step, f64> = HashMap::new();
+        let mut came_from: HashMap<Step, Step> = HashMap::new();
+        let mut source_of: HashMap<Step, usize> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        // The straight line distance to the nearest target never overestimates the remaining cost
+        let heuristic = |node: NodeId| {
+            targets
+                .iter()
+                .map(|target| distance(self.point(node), self.point(target.node)))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        for (index, source) in sources.iter().enumerate() {
+            let step = (source.node, source.partial.as_ref().map(|(edge, _)| *edge));
+
+            if cost.get(&step).is_none_or(|&cost| source.cost < cost) {
+                cost.insert(step, source.cost);
+                source_of.insert(step, index);
+                heap.push(Pending {
+                    estimate: source.cost + heuristic(source.node),
+                    step,
+                });
+            }
+        }
+
+        let mut best: Option<(f64, Step, &Access)> = None;
+
+        while let Some(Pending { estimate, step }) = heap.pop() {
+            if best.is_some_and(|(best_cost, ..)| estimate >= best_cost) {
+                break;
+            }
+
+            let (node, incoming) = step;
+            let step_cost = cost[&step];
+
+            // A step reached again more cheaply since it was queued has already been searched from
+            if estimate > step_cost + heuristic(node) {
+                continue;
+            }
+
+            let reachable = targets
+                .iter()
+                .filter(|target| target.node == node)
+                .filter_map(|target| {
+                    let extra = match &target.partial {
+                        Some((edge, _)) => entry(incoming, *edge, node)?,
+                        None => 0.0,
+                    };
+
+                    Some((target, extra))
+                });
+
+            for (target, extra) in reachable {
+                let total = step_cost + target.cost + extra;
+
+                if best.is_none_or(|(best_cost, ..)| total < best_cost) {
+                    best = Some((total, step, target));
+                }
+            }
+
+            for (edge, candidate) in self.edges_at(node) {
+                if candidate.kind == EdgeKind::Runway {
+                    continue;
+                }
+
+                let Some(extra) = entry(incoming, edge, node) else {
+                    continue;
+                };
+
+                let next = (candidate.other(node), Some(edge));
+                let next_cost = step_cost + candidate.length + extra;
+
+                if cost.get(&next).is_none_or(|&cost| next_cost < cost) {
+                    cost.insert(next, next_cost);
+                    came_from.insert(next, step);
+                    heap.push(Pending {
+                        estimate: next_cost + heuristic(next.0),
+                        step: next,
+                    });
+                }
+            }
+        }
+
+        let (length, last, target) = best?;
+
+        let mut steps = vec![last];
+        let mut step = last;
+
+        while let Some(&previous) = came_from.get(&step) {
+            steps.push(previous);
+            step = previous;
+        }
+
+        steps.reverse();
+
+        let source = &sources[*source_of.get(&step)?];
+
+        let mut edges = Vec::new();
+        let mut geometry = Vec::new();
+
+        if let Some((edge, coords)) = &source.partial {
+            edges.push(*edge);
+            geometry.extend(coords);
+        }
+
+        for (&(previous, _), &(_, edge)) in steps.iter().zip(&steps[1..]) {
+            let edge = edge?;
+            let line = &self.edge(edge).geometry.0;
+
+            edges.push(edge);
+
+            match self.edge(edge).from == previous {
+                true => geometry.extend(line),
+                false => geometry.extend(line.iter().rev()),
+            }
+        }
+
+        if let Some((edge, coords)) = &target.partial {
+            edges.push(*edge);
+            geometry.extend(coords.iter().rev());
+        }
+
+        let nodes = steps.into_iter().map(|(node, _)| node).collect();
+
+        Some(self.assemble(edges, nodes, geometry, length))
+    }
+
+    fn assemble(&self, edges: Vec<EdgeId>, nodes: Vec<NodeId>, mut geometry: Vec<Coord>, length: f64) -> TaxiRoute {
+        geometry.dedup();
+
+        let mut taxiway_ids: Vec<String> = Vec::new();
+
+        for taxiway_id in edges.iter().filter_map(|&edge| self.edge(edge).taxiway_id.as_ref()) {
+            if taxiway_ids.last() != Some(taxiway_id) {
+                taxiway_ids.push(taxiway_id.clone());
+            }
+        }
+
+        TaxiRoute {
+            edges,
+            nodes,
+            geometry: LineString::new(geometry),
+            length,
+            taxiway_ids,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::OnceLock;
+
+use geo::{BoundingRect, Coord, Intersects, MultiLineString, MultiPolygon, Rect};
+
+use crate::{
+    geometry::{chain_lines, combine, dissolve},
+    output_types::{
+        HoldingPointTarget,
+        RunwayExitLine,
+        RunwayId,
+        TaxiwayElement,
+        TaxiwayGuidanceLine,
+        TaxiwayHoldingPosition,
+    },
+    Airport,
+};
+
+/// A taxiway, grouping the features which share its designator.
+///
+/// Related features are referenced by id, and can be resolved against the airport the taxiway was built from.
+#[derive(Debug, Clone)]
+pub struct Taxiway {
+    /// The designator of the taxiway, such as `A` or `B2`
+    pub designator: String,
+    outline: OnceLock<MultiPolygon>,
+    /// The guidance lines of the taxiway joined into continuous chains, one part for each branch
+    pub centerline: MultiLineString,
+    pub element_ids: Vec<u64>,
+    pub guidance_line_ids: Vec<u64>,
+    pub exit_line_ids: Vec<u64>,
+    pub holding_position_ids: Vec<u64>,
+    connected_runways: OnceLock<Vec<RunwayId>>,
+    connected_aprons: OnceLock<Vec<String>>,
+}
+
+impl Taxiway {
+    /// The polygons of the taxiway elements taken together, without dissolving the edges they share. They are gathered
+    /// from the airport each time, rather than being held alongside the elements.
+    pub fn surface(&self, airport: &Airport) -> MultiPolygon {
+        combine(self.elements(airport).map(|element| &element.geometry))
+    }
+
+    /// The union of the taxiway elements, which is dissolved the first time it is asked for. The airport must be the
+    /// one the taxiway was built from.
+    pub fn outline(&self, airport: &Airport) -> &MultiPolygon {
+        self.outline.get_or_init(|| dissolve(&self.surface(airport)))
+    }
+
+    pub fn elements<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a TaxiwayElement> {
+        airport
+            .taxiway_elements
+            .iter()
+            .filter(|element| self.element_ids.contains(&element.id))
+    }
+
+    pub fn guidance_lines<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a TaxiwayGuidanceLine> {
+        airport
+            .taxiway_guidance_lines
+            .iter()
+            .filter(|guidance_line| self.guidance_line_ids.contains(&guidance_line.id))
+    }
+
+    pub fn exit_lines<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a RunwayExitLine> {
+        airport
+            .runway_exit_lines
+            .iter()
+            .filter(|exit_line| self.exit_line_ids.contains(&exit_line.id))
+    }
+
+    pub fn holding_positions<'a>(&'a self, airport: &'a Airport) -> impl Iterator<Item = &'a TaxiwayHoldingPosition> {
+        airport
+            .taxiway_holding_positions
+            .iter()
+            .filter(|position| self.holding_position_ids.contains(&position.id))
+    }
+
+    /// The runways the taxiway leads onto, either through its holding positions or by touching the runway. They are
+    /// found the first time they are asked for, and the airport must be the one the taxiway was built from.
+    pub fn connected_runways(&self, airport: &Airport) -> &[RunwayId] {
+        self.connected_runways.get_or_init(|| {
+            let bounds = self.bounds(airport);
+            let exit_lines: Vec<_> = self.exit_lines(airport).collect();
+
+            let targets = self
+                .holding_positions(airport)
+                .filter_map(|position| match &position.holding_point_target {
+                    Some(HoldingPointTarget::Runway(runway_id)) => Some(runway_id),
+                    _ => None,
+                });
+            let touching = airport
+                .runways()
+                .iter()
+                .filter(|runway| {
+                    runway.elements(airport).any(|element| {
+                        overlaps(&bounds, &element.geometry)
+                            && (self
+                                .elements(airport)
+                                .any(|own| own.geometry.intersects(&element.geometry))
+                                || exit_lines
+                                    .iter()
+                                    .any(|exit_line| exit_line.geometry.intersects(&element.geometry)))
+                    })
+                })
+                .map(|runway| &runway.runway_id);
+
+            let mut connected_runways: Vec<RunwayId> = Vec::new();
+
+            for runway_id in targets.chain(touching) {
+                if !connected_runways.iter().any(|known| known.same_runway(runway_id)) {
+                    connected_runways.push(runway_id.clone());
+                }
+            }
+
+            connected_runways
+        })
+    }
+
+    /// The aprons the taxiway leads onto, either as referenced by its elements or by touching the apron. They are
+    /// found the first time they are asked for, and the airport must be the one the taxiway was built from.
+    pub fn connected_aprons(&self, airport: &Airport) -> &[String] {
+        self.connected_aprons.get_or_init(|| {
+            let bounds = self.bounds(airport);
+
+            let referenced = self.elements(airport).filter_map(|element| element.apron_id.as_ref());
+            let touching = airport
+                .apron_elements
+                .iter()
+                .filter(|apron| {
+                    overlaps(&bounds, &apron.geometry)
+                        && self
+                            .elements(airport)
+                            .any(|element| element.geometry.intersects(&apron.geometry))
+                })
+                .filter_map(|apron| apron.apron_id.as_ref());
+
+            let mut connected_aprons: Vec<String> = Vec::new();
+
+            for apron_id in referenced.chain(touching) {
+                if !connected_aprons.contains(apron_id) {
+                    connected_aprons.push(apron_id.clone());
+                }
+            }
+
+            connected_aprons
+        })
+    }
+
+    /// The box enclosing the elements and exit lines of the taxiway, or None if they have no geometry
+    fn bounds(&self, airport: &Airport) -> Option<Rect> {
+        self.elements(airport)
+            .filter_map(|element| element.geometry.bounding_rect())
+            .chain(
+                self.exit_lines(airport)
+                    .filter_map(|exit_line| exit_line.geometry.bounding_rect()),
+            )
+            .reduce(|a, b| {
+                Rect::new(
+                    Coord {
+                        x: a.min().x.min(b.min().x),
+                        y: a.min().y.min(b.min().y),
+                    },
+                    Coord {
+                        x: a.max().x.max(b.max().x),
+                        y: a.max().y.max(b.max().y),
+                    },
+                )
+            })
+    }
+
+    fn new(airport: &Airport, designator: String) -> Self {
+        let on_taxiway = |taxiway_id: &Option<String>| taxiway_id.as_ref() == Some(&designator);
+
+        let guidance_lines: Vec<_> = airport
+            .taxiway_guidance_lines
+            .iter()
+            .filter(|guidance_line| on_taxiway(&guidance_line.taxiway_id))
+            .collect();
+
+        Self {
+            outline: OnceLock::new(),
+            centerline: chain_lines(guidance_lines.iter().flat_map(|guidance_line| &guidance_line.geometry)),
+            element_ids: airport
+                .taxiway_elements
+                .iter()
+                .filter(|element| on_taxiway(&element.taxiway_id))
+                .map(|element| element.id)
+                .collect(),
+            guidance_line_ids: guidance_lines.iter().map(|guidance_line| guidance_line.id).collect(),
+            exit_line_ids: airport
+                .runway_exit_lines
+                .iter()
+                .filter(|exit_line| on_taxiway(&exit_line.taxiway_id))
+                .map(|exit_line| exit_line.id)
+                .collect(),
+            holding_position_ids: airport
+                .taxiway_holding_positions
+                .iter()
+                .filter(|position| on_taxiway(&position.taxiway_id))
+                .map(|position| position.id)
+                .collect(),
+            connected_runways: OnceLock::new(),
+            connected_aprons: OnceLock::new(),
+            designator,
+        }
+    }
+}
+
+/// Whether a shape could touch a box, judged by the box enclosing the shape
+fn overlaps(bounds: &Option<Rect>, shape: &impl BoundingRect<f64, Output = Option<Rect>>) -> bool {
+    match (bounds, shape.bounding_rect()) {
+        (Some(bounds), Some(rect)) => bounds.intersects(&rect),
+        _ => false,
+    }
+}
+
+/// Builds the taxiways of an airport from every designator referenced by its taxiway elements, guidance lines, exit
+/// lines and holding positions, in the order they first appear
+pub(crate) fn build_taxiways(airport: &Airport) -> Vec<Taxiway> {
+    let mut designators: Vec<&String> = Vec::new();
+
+    let referenced = airport
+        .taxiway_elements
+        .iter()
+        .filter_map(|element| element.taxiway_id.as_ref())
+        .chain(
+            airport
+                .taxiway_guidance_lines
+                .iter()
+                .filter_map(|line| line.taxiway_id.as_ref()),
+        )
+        .chain(
+            airport
+                .runway_exit_lines
+                .iter()
+                .filter_map(|line| line.taxiway_id.as_ref()),
+        )
+        .chain(
+            airport
+                .taxiway_holding_positions
+                .iter()
+                .filter_map(|position| position.taxiway_id.as_ref()),
+        );
+
+    for designator in referenced {
+        if !designators.contains(&designator) {
+            designators.push(designator);
+        }
+    }
+
+    designators
+        .into_iter()
+        .map(|designator| Taxiway::new(airport, designator.clone()))
+        .collect()
+}
@@ -0,0 +1,720 @@
+use std::iter;
+
+use geo::{Coord, LineString, MultiLineString, MultiPolygon};
+use serde::{
+    ser::{SerializeMap, SerializeStruct},
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    amdb::{
+        geo_json::{self, Coordinate, LineStringGeometry, PointGeometry, PolygonGeometry},
+        layers,
+    },
+    layer::Layer,
+    output_types::*,
+    Airport,
+};
+
+/// Replaces None with the $UNK string AMDB uses for unknown values
+fn denormalize_string(s: &Option<String>) -> String { s.clone().unwrap_or_else(|| String::from("$UNK")) }
+
+/// Joins a list such as an aircraft type list back into its dot separated form
+fn join_list(list: &[String]) -> String {
+    match list {
+        [] => String::from("$UNK"),
+        list => list.join("."),
+    }
+}
+
+fn coordinates(line: &LineString, elevations: Option<&Vec<f64>>) -> Vec<Coordinate> {
+    line.coords()
+        .enumerate()
+        .map(|(index, coord)| Coordinate {
+            lat: coord.y,
+            lon: coord.x,
+            elevation: elevations.and_then(|elevations| elevations.get(index).copied()),
+        })
+        .collect()
+}
+
+fn point_feature<T>(properties: T, location: Coord, elevation: Option<f64>) -> geo_json::Point<T> {
+    geo_json::Point {
+        geometry: PointGeometry::Point(Coordinate {
+            lat: location.y,
+            lon: location.x,
+            elevation,
+        }),
+        properties,
+    }
+}
+
+/// Builds a line feature, writing single part geometries as a LineString
+fn line_feature<T>(
+    properties: T, geometry: &MultiLineString, elevations: &Option<LineElevations>,
+) -> geo_json::LineString<T> {
+    let mut lines: Vec<_> = geometry
+        .iter()
+        .enumerate()
+        .map(|(part, line)| coordinates(line, elevations.as_ref().and_then(|elevations| elevations.get(part))))
+        .collect();
+
+    geo_json::LineString {
+        geometry: match lines.len() {
+            1 => LineStringGeometry::LineString(lines.remove(0)),
+            _ => LineStringGeometry::MultiLineString(lines),
+        },
+        properties,
+    }
+}
+
+/// Builds a polygon feature, writing single part geometries as a Polygon
+fn polygon_feature<T>(
+    properties: T, geometry: &MultiPolygon, elevations: &Option<PolygonElevations>,
+) -> geo_json::Polygon<T> {
+    let mut polygons: Vec<_> = geometry
+        .iter()
+        .enumerate()
+        .map(|(part, polygon)| {
+            let elevations = elevations.as_ref().and_then(|elevations| elevations.get(part));
+
+            iter::once(polygon.exterior())
+                .chain(polygon.interiors())
+                .enumerate()
+                .map(|(ring, line)| coordinates(line, elevations.and_then(|elevations| elevations.get(ring))))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    geo_json::Polygon {
+        geometry: match polygons.len() {
+            1 => PolygonGeometry::Polygon(polygons.remove(0)),
+            _ => PolygonGeometry::MultiPolygon(polygons),
+        },
+        properties,
+        reoriented: false,
+    }
+}
+
+impl From<&AerodromeReferencePoint> for geo_json::Point<layers::AerodromeReferencePoint> {
+    fn from(reference_point: &AerodromeReferencePoint) -> Self {
+        point_feature(
+            layers::AerodromeReferencePoint {
+                id: reference_point.id,
+                idarpt: reference_point.airport_id.clone(),
+                iata: reference_point.iata_id.clone(),
+                name: reference_point.airport_name.clone(),
+                elev: reference_point.elevation,
+            },
+            reference_point.location,
+            reference_point.location_elevation,
+        )
+    }
+}
+
+impl From<&ApronElement> for geo_json::Polygon<layers::ApronElement> {
+    fn from(apron: &ApronElement) -> Self {
+        polygon_feature(
+            layers::ApronElement {
+                id: apron.id,
+                idapron: Some(denormalize_string(&apron.apron_id)),
+                gsurftyp: apron.surface_type,
+                status: apron.status,
+            },
+            &apron.geometry,
+            &apron.vertex_elevations,
+        )
+    }
+}
+
+impl From<&Blastpad> for geo_json::Polygon<layers::Blastpad> {
+    fn from(blastpad: &Blastpad) -> Self {
+        polygon_feature(
+            layers::Blastpad {
+                id: blastpad.id,
+                idthr: blastpad.threshold_id.clone(),
+            },
+            &blastpad.geometry,
+            &blastpad.vertex_elevations,
+        )
+    }
+}
+
+impl From<&ConstructionArea> for geo_json::Polygon<layers::ConstructionArea> {
+    fn from(area: &ConstructionArea) -> Self {
+        polygon_feature(
+            layers::ConstructionArea {
+                id: area.id,
+                pstdate: denormalize_string(&area.planned_start_date),
+                pendate: denormalize_string(&area.planned_end_date),
+                piocdate: denormalize_string(&area.planned_operational_date),
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&DeicingArea> for geo_json::Polygon<layers::DeicingArea> {
+    fn from(area: &DeicingArea) -> Self {
+        polygon_feature(
+            layers::DeicingArea {
+                id: area.id,
+                idbase: Some(denormalize_string(&area.base_id)),
+                gsurftyp: area.surface_type,
+                ident: denormalize_string(&area.deicing_area_id),
+                status: area.status,
+                restacn: Some(join_list(&area.restricted_aircraft_types)),
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&FinalApproachAndTakeoffArea> for geo_json::Polygon<layers::FinalApproachAndTakeoffArea> {
+    fn from(area: &FinalApproachAndTakeoffArea) -> Self {
+        polygon_feature(
+            layers::FinalApproachAndTakeoffArea {
+                id: area.id,
+                idrwy: Some(denormalize_string(&area.runway_id)),
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&FrequencyArea> for geo_json::Polygon<layers::FrequencyArea> {
+    fn from(area: &FrequencyArea) -> Self {
+        polygon_feature(
+            layers::FrequencyArea {
+                id: area.id,
+                frq: area.frequency,
+                station: Some(denormalize_string(&area.station)),
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&Hotspot> for geo_json::Polygon<layers::Hotspot> {
+    fn from(hotspot: &Hotspot) -> Self {
+        polygon_feature(
+            layers::Hotspot {
+                id: hotspot.id,
+                idhot: Some(denormalize_string(&hotspot.hotspot_id)),
+            },
+            &hotspot.geometry,
+            &hotspot.vertex_elevations,
+        )
+    }
+}
+
+impl From<&LandAndHoldShortOperationLocation> for geo_json::LineString<layers::LandAndHoldShortOperationLocation> {
+    fn from(location: &LandAndHoldShortOperationLocation) -> Self {
+        line_feature(
+            layers::LandAndHoldShortOperationLocation {
+                id: location.id,
+                idp: denormalize_string(&location.holding_point_target.as_ref().map(ToString::to_string)),
+                idthr: location.threshold_id.clone(),
+            },
+            &location.geometry,
+            &location.vertex_elevations,
+        )
+    }
+}
+
+impl From<&PaintedCenterline> for geo_json::LineString<layers::PaintedCenterline> {
+    fn from(centerline: &PaintedCenterline) -> Self {
+        line_feature(
+            layers::PaintedCenterline {
+                id: centerline.id,
+                idrwy: centerline.runway_id.to_string(),
+            },
+            &centerline.geometry,
+            &centerline.vertex_elevations,
+        )
+    }
+}
+
+impl From<&ParkingStandArea> for geo_json::Polygon<layers::ParkingStandArea> {
+    fn from(area: &ParkingStandArea) -> Self {
+        polygon_feature(
+            layers::ParkingStandArea {
+                id: area.id,
+                idstd: Some(denormalize_string(&area.stand_id)),
+                gsurftyp: area.surface_type,
+                idapron: Some(denormalize_string(&area.apron_id)),
+                jetway: area.jetway,
+                fuel: area.fuel.clone(),
+                restacn: Some(join_list(&area.restricted_aircraft_types)),
+                towing: area.towing,
+                gndpower: area.ground_power,
+                termref: Some(denormalize_string(&area.terminal_name)),
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&ParkingStandLocation> for geo_json::Point<layers::ParkingStandLocation> {
+    fn from(location: &ParkingStandLocation) -> Self {
+        point_feature(
+            layers::ParkingStandLocation {
+                id: location.id,
+                idstd: Some(denormalize_string(&location.stand_id)),
+                acn: Some(join_list(&location.aircraft_types)),
+                termref: Some(denormalize_string(&location.terminal_name)),
+            },
+            location.location,
+            location.location_elevation,
+        )
+    }
+}
+
+impl From<&RunwayDisplacedArea> for geo_json::Polygon<layers::RunwayDisplacedArea> {
+    fn from(area: &RunwayDisplacedArea) -> Self {
+        polygon_feature(
+            layers::RunwayDisplacedArea {
+                id: area.id,
+                idthr: area.threshold_id.clone(),
+                status: area.status,
+                surftype: area.surface_type,
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayElement> for geo_json::Polygon<layers::RunwayElement> {
+    fn from(element: &RunwayElement) -> Self {
+        polygon_feature(
+            layers::RunwayElement {
+                id: element.id,
+                idrwy: element.runway_id.to_string(),
+                width: element.width,
+                length: element.length,
+                surftype: element.surface_type,
+            },
+            &element.geometry,
+            &element.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayExitLine> for geo_json::LineString<layers::RunwayExitLine> {
+    fn from(exit_line: &RunwayExitLine) -> Self {
+        line_feature(
+            layers::RunwayExitLine {
+                id: exit_line.id,
+                idlin: Some(denormalize_string(&exit_line.taxiway_id)),
+                status: exit_line.status,
+                direc: exit_line.direction,
+                color: exit_line.color,
+                style: exit_line.style,
+            },
+            &exit_line.geometry,
+            &exit_line.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayIntersection> for geo_json::Polygon<layers::RunwayIntersection> {
+    fn from(intersection: &RunwayIntersection) -> Self {
+        polygon_feature(
+            layers::RunwayIntersection {
+                id: intersection.id,
+                idrwi: intersection.intersection_id.clone(),
+                surftype: intersection.surface_type,
+            },
+            &intersection.geometry,
+            &intersection.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayMarking> for geo_json::Polygon<layers::RunwayMarking> {
+    fn from(marking: &RunwayMarking) -> Self {
+        polygon_feature(
+            layers::RunwayMarking {
+                id: marking.id,
+                idrwy: marking.runway_id.to_string(),
+            },
+            &marking.geometry,
+            &marking.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayShoulder> for geo_json::Polygon<layers::RunwayShoulder> {
+    fn from(shoulder: &RunwayShoulder) -> Self {
+        polygon_feature(
+            layers::RunwayShoulder {
+                id: shoulder.id,
+                idrwy: shoulder.runway_id.to_string(),
+                status: shoulder.status,
+                gsurftyp: shoulder.surface_type,
+            },
+            &shoulder.geometry,
+            &shoulder.vertex_elevations,
+        )
+    }
+}
+
+impl From<&RunwayThreshold> for geo_json::Point<layers::RunwayThreshold> {
+    fn from(threshold: &RunwayThreshold) -> Self {
+        point_feature(
+            layers::RunwayThreshold {
+                id: threshold.id,
+                idthr: threshold.threshold_id.clone(),
+                tdze: threshold.touch_down_zone_elevation,
+                tdzslope: threshold.touch_down_zone_slope,
+                brngtrue: threshold.true_bearing,
+                brngmag: threshold.magnetic_bearing,
+                rwyslope: threshold.runway_slope,
+                tora: threshold.takeoff_run_available,
+                toda: threshold.takeoff_distance_available,
+                asda: threshold.accelerate_stop_distance_available,
+                lda: threshold.landing_distance_available,
+                cat: threshold.category,
+                status: threshold.status,
+                thrtype: threshold.threshold_type,
+                vasis: threshold.papivasi,
+            },
+            threshold.location,
+            threshold.location_elevation,
+        )
+    }
+}
+
+impl From<&ServiceRoad> for geo_json::Polygon<layers::ServiceRoad> {
+    fn from(road: &ServiceRoad) -> Self {
+        polygon_feature(
+            layers::ServiceRoad {
+                id: road.id,
+                gsurftyp: road.surface_type,
+                idbase: Some(denormalize_string(&road.base_id)),
+            },
+            &road.geometry,
+            &road.vertex_elevations,
+        )
+    }
+}
+
+impl From<&StandGuidanceLine> for geo_json::LineString<layers::StandGuidanceLine> {
+    fn from(guidance_line: &StandGuidanceLine) -> Self {
+        line_feature(
+            layers::StandGuidanceLine {
+                id: guidance_line.id,
+                idstd: Some(denormalize_string(&guidance_line.stand_id)),
+                status: guidance_line.status,
+                direc: guidance_line.direction,
+                color: guidance_line.color,
+                style: guidance_line.style,
+                termref: Some(denormalize_string(&guidance_line.terminal_name)),
+            },
+            &guidance_line.geometry,
+            &guidance_line.vertex_elevations,
+        )
+    }
+}
+
+impl From<&Stopway> for geo_json::Polygon<layers::Stopway> {
+    fn from(stopway: &Stopway) -> Self {
+        polygon_feature(
+            layers::Stopway {
+                id: stopway.id,
+                idthr: stopway.threshold_id.clone(),
+                status: stopway.status,
+                surftype: stopway.surface_type,
+            },
+            &stopway.geometry,
+            &stopway.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TaxiwayElement> for geo_json::Polygon<layers::TaxiwayElement> {
+    fn from(element: &TaxiwayElement) -> Self {
+        polygon_feature(
+            layers::TaxiwayElement {
+                id: element.id,
+                idlin: Some(denormalize_string(&element.taxiway_id)),
+                idapron: Some(denormalize_string(&element.apron_id)),
+                gsurftyp: element.surface_type,
+                bridge: element.bridge,
+            },
+            &element.geometry,
+            &element.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TaxiwayGuidanceLine> for geo_json::LineString<layers::TaxiwayGuidanceLine> {
+    fn from(guidance_line: &TaxiwayGuidanceLine) -> Self {
+        line_feature(
+            layers::TaxiwayGuidanceLine {
+                id: guidance_line.id,
+                idlin: Some(denormalize_string(&guidance_line.taxiway_id)),
+                status: guidance_line.status,
+                direc: guidance_line.direction,
+                color: guidance_line.color,
+                style: guidance_line.style,
+            },
+            &guidance_line.geometry,
+            &guidance_line.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TaxiwayHoldingPosition> for geo_json::LineString<layers::TaxiwayHoldingPosition> {
+    fn from(position: &TaxiwayHoldingPosition) -> Self {
+        line_feature(
+            layers::TaxiwayHoldingPosition {
+                id: position.id,
+                idp: Some(denormalize_string(
+                    &position.holding_point_target.as_ref().map(ToString::to_string),
+                )),
+                idlin: Some(denormalize_string(&position.taxiway_id)),
+                status: position.status,
+                catstop: position.category,
+            },
+            &position.geometry,
+            &position.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TaxiwayIntersectionMarking> for geo_json::LineString<layers::TaxiwayIntersectionMarking> {
+    fn from(marking: &TaxiwayIntersectionMarking) -> Self {
+        line_feature(
+            layers::TaxiwayIntersectionMarking {
+                id: marking.id,
+                idlin: denormalize_string(&marking.taxiway_id),
+            },
+            &marking.geometry,
+            &marking.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TaxiwayShoulder> for geo_json::Polygon<layers::TaxiwayShoulder> {
+    fn from(shoulder: &TaxiwayShoulder) -> Self {
+        polygon_feature(
+            layers::TaxiwayShoulder {
+                id: shoulder.id,
+                gsurftyp: shoulder.surface_type,
+                status: shoulder.status,
+            },
+            &shoulder.geometry,
+            &shoulder.vertex_elevations,
+        )
+    }
+}
+
+impl From<&TouchdownLiftoffArea> for geo_json::Polygon<layers::TouchdownLiftoffArea> {
+    fn from(area: &TouchdownLiftoffArea) -> Self {
+        polygon_feature(
+            layers::TouchdownLiftoffArea {
+                id: area.id,
+                idrwy: Some(denormalize_string(&area.runway_id)),
+                surftype: area.surface_type,
+            },
+            &area.geometry,
+            &area.vertex_elevations,
+        )
+    }
+}
+
+impl From<&VerticalLineStructure> for geo_json::LineString<layers::VerticalLineStructure> {
+    fn from(structure: &VerticalLineStructure) -> Self {
+        line_feature(
+            layers::VerticalLineStructure {
+                id: structure.id,
+                linsttyp: structure.structure_type,
+                material: structure.material,
+                height: structure.height,
+                elev: structure.elevation,
+                lighting: structure.lighting,
+                marking: structure.marking,
+            },
+            &structure.geometry,
+            &structure.vertex_elevations,
+        )
+    }
+}
+
+impl From<&VerticalPointStructure> for geo_json::Point<layers::VerticalPointStructure> {
+    fn from(structure: &VerticalPointStructure) -> Self {
+        point_feature(
+            layers::VerticalPointStructure {
+                id: structure.id,
+                pntsttyp: structure.structure_type,
+                material: structure.material,
+                height: structure.height,
+                elev: structure.elevation,
+                lighting: structure.lighting,
+                radius: structure.radius,
+                marking: structure.marking,
+            },
+            structure.location,
+            structure.location_elevation,
+        )
+    }
+}
+
+impl From<&VerticalPolygonalStructure> for geo_json::Polygon<layers::VerticalPolygonalStructure> {
+    fn from(structure: &VerticalPolygonalStructure) -> Self {
+        polygon_feature(
+            layers::VerticalPolygonalStructure {
+                id: structure.id,
+                ident: Some(denormalize_string(&structure.name)),
+                plysttyp: structure.structure_type,
+                material: structure.material,
+                height: structure.height,
+                elev: structure.elevation,
+            },
+            &structure.geometry,
+            &structure.vertex_elevations,
+        )
+    }
+}
+
+impl From<&Water> for geo_json::Polygon<layers::Water> {
+    fn from(water: &Water) -> Self {
+        polygon_feature(
+            layers::Water { id: water.id },
+            &water.geometry,
+            &water.vertex_elevations,
+        )
+    }
+}
+
+/// Serializes an output type as the AMDB GeoJSON feature it was parsed from
+macro_rules! serialize_as {
+    ($type:ident : $feature:ident) => {
+        impl Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                geo_json::$feature::<layers::$type>::from(self).serialize(serializer)
+            }
+        }
+    };
+}
+
+serialize_as!(AerodromeReferencePoint: Point);
+serialize_as!(ApronElement: Polygon);
+serialize_as!(Blastpad: Polygon);
+serialize_as!(ConstructionArea: Polygon);
+serialize_as!(DeicingArea: Polygon);
+serialize_as!(FinalApproachAndTakeoffArea: Polygon);
+serialize_as!(FrequencyArea: Polygon);
+serialize_as!(Hotspot: Polygon);
+serialize_as!(LandAndHoldShortOperationLocation: LineString);
+serialize_as!(PaintedCenterline: LineString);
+serialize_as!(ParkingStandArea: Polygon);
+serialize_as!(ParkingStandLocation: Point);
+serialize_as!(RunwayDisplacedArea: Polygon);
+serialize_as!(RunwayElement: Polygon);
+serialize_as!(RunwayExitLine: LineString);
+serialize_as!(RunwayIntersection: Polygon);
+serialize_as!(RunwayMarking: Polygon);
+serialize_as!(RunwayShoulder: Polygon);
+serialize_as!(RunwayThreshold: Point);
+serialize_as!(ServiceRoad: Polygon);
+serialize_as!(StandGuidanceLine: LineString);
+serialize_as!(Stopway: Polygon);
+serialize_as!(TaxiwayElement: Polygon);
+serialize_as!(TaxiwayGuidanceLine: LineString);
+serialize_as!(TaxiwayHoldingPosition: LineString);
+serialize_as!(TaxiwayIntersectionMarking: LineString);
+serialize_as!(TaxiwayShoulder: Polygon);
+serialize_as!(TouchdownLiftoffArea: Polygon);
+serialize_as!(VerticalLineStructure: LineString);
+serialize_as!(VerticalPointStructure: Point);
+serialize_as!(VerticalPolygonalStructure: Polygon);
+serialize_as!(Water: Polygon);
+
+/// Serializes the features of a layer as a GeoJSON FeatureCollection
+struct FeatureCollection<'a, T>(&'a [T]);
+
+impl<'a, T: Serialize> Serialize for FeatureCollection<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut collection = serializer.serialize_struct("FeatureCollection", 2)?;
+        collection.serialize_field("type", "FeatureCollection")?;
+        collection.serialize_field("features", self.0)?;
+        collection.end()
+    }
+}
+
+/// Serializes the airport in the AMDB GeoJSON layout it is parsed from.
+///
+/// Layers are written if they were present in the source document or contain any features.
+impl Serialize for Airport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry(
+            Layer::AerodromeReferencePoint.name(),
+            &FeatureCollection(std::slice::from_ref(&self.aerodrome_reference_point)),
+        )?;
+
+        macro_rules! layer {
+            ($layer:ident, $features:ident) => {
+                if self.has_layer(Layer::$layer) || !self.$features.is_empty() {
+                    map.serialize_entry(Layer::$layer.name(), &FeatureCollection(&self.$features))?;
+                }
+            };
+        }
+
+        layer!(ApronElement, apron_elements);
+        layer!(Blastpad, blastpads);
+        layer!(ConstructionArea, construction_areas);
+        layer!(DeicingArea, deicing_areas);
+        layer!(FinalApproachAndTakeoffArea, final_approach_and_takeoff_areas);
+        layer!(FrequencyArea, frequency_areas);
+        layer!(Hotspot, hotspots);
+        layer!(
+            LandAndHoldShortOperationLocation,
+            land_and_hold_short_operation_locations
+        );
+        layer!(PaintedCenterline, painted_centerlines);
+        layer!(ParkingStandArea, parking_stand_areas);
+        layer!(ParkingStandLocation, parking_stand_locations);
+        layer!(RunwayDisplacedArea, runway_displaced_areas);
+        layer!(RunwayElement, runway_elements);
+        layer!(RunwayExitLine, runway_exit_lines);
+        layer!(RunwayIntersection, runway_intersections);
+        layer!(RunwayMarking, runway_markings);
+        layer!(RunwayShoulder, runway_shoulders);
+        layer!(RunwayThreshold, runway_thresholds);
+        layer!(ServiceRoad, service_roads);
+        layer!(StandGuidanceLine, stand_guidance_lines);
+        layer!(Stopway, stopways);
+        layer!(TaxiwayElement, taxiway_elements);
+        layer!(TaxiwayGuidanceLine, taxiway_guidance_lines);
+        layer!(TaxiwayHoldingPosition, taxiway_holding_positions);
+        layer!(TaxiwayIntersectionMarking, taxiway_intersection_markings);
+        layer!(TaxiwayShoulder, taxiway_shoulders);
+        layer!(TouchdownLiftoffArea, touchdown_liftoff_areas);
+        layer!(VerticalLineStructure, vertical_line_structures);
+        layer!(VerticalPointStructure, vertical_point_structures);
+        layer!(VerticalPolygonalStructure, vertical_polygonal_structures);
+        layer!(Water, water);
+
+        map.end()
+    }
+}
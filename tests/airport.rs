@@ -0,0 +1,53 @@
+use amdb_geo::{parse_airport, taxi_route::RouteLocation, Airport};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+#[test]
+fn builds_aggregates_from_layers() {
+    let parsed = parse_airport(AIRPORT).unwrap();
+
+    let mut airport = Airport::default();
+    airport.aerodrome_reference_point = parsed.aerodrome_reference_point;
+    airport.runway_elements = parsed.runway_elements;
+    airport.runway_thresholds = parsed.runway_thresholds;
+    airport.parking_stand_locations = parsed.parking_stand_locations;
+    airport.stand_guidance_lines = parsed.stand_guidance_lines;
+    airport.taxiway_elements = parsed.taxiway_elements;
+    airport.taxiway_guidance_lines = parsed.taxiway_guidance_lines;
+
+    assert!(airport.runways().is_empty());
+
+    airport.rebuild_aggregates();
+
+    assert_eq!(airport.runways().len(), 1);
+    assert_eq!(airport.runways()[0].ends[1].threshold, Some(7));
+    assert_eq!(airport.taxiways().len(), 4);
+    assert!(airport.stand("214").is_some());
+}
+
+#[test]
+fn rebuilds_aggregates_after_filtering() {
+    let mut airport = parse_airport(AIRPORT).unwrap();
+    let from = RouteLocation::Stand(String::from("214"));
+    let to = RouteLocation::HoldingPosition(18);
+
+    airport
+        .taxiway_guidance_lines
+        .retain(|line| line.taxiway_id.as_deref() != Some("B"));
+
+    // Until rebuilt, the taxiway still refers to the removed line
+    assert_eq!(airport.taxiway("B").unwrap().guidance_line_ids, [15]);
+
+    airport.rebuild_aggregates();
+
+    assert!(airport.taxiway("B").unwrap().guidance_line_ids.is_empty());
+
+    let route = airport.taxi_graph().route(&from, &to).unwrap();
+    assert_eq!(route.taxiway_ids, ["E", "K", "A"]);
+
+    airport.parking_stand_locations.clear();
+    airport.stand_guidance_lines.clear();
+    airport.rebuild_aggregates();
+
+    assert!(airport.stands().is_empty());
+}
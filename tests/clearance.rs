@@ -0,0 +1,66 @@
+use amdb_geo::{output_types::RunwayDesignator, parse_airport, taxi_route::RouteLocation, Airport};
+use geo::Coord;
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+fn stand() -> RouteLocation { RouteLocation::Stand(String::from("214")) }
+
+/// The clearance along the route between two locations, with the given runway ends in use
+fn clearance(airport: &Airport, from: &RouteLocation, to: &RouteLocation, runways_in_use: &[&str]) -> String {
+    let graph = airport.taxi_graph();
+    let route = graph.route(from, to).unwrap();
+    let runways_in_use: Vec<_> = runways_in_use
+        .iter()
+        .map(|&designator| RunwayDesignator::try_from(designator).unwrap())
+        .collect();
+
+    route.clearance(airport, &graph, to, &runways_in_use).to_string()
+}
+
+#[test]
+fn clears_to_the_holding_point() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let holding_point = RouteLocation::HoldingPosition(18);
+
+    // The holding point lies nearer the end of runway 09
+    assert_eq!(
+        clearance(&airport, &stand(), &holding_point, &[]),
+        "Taxi to holding point A runway 09 via B, A"
+    );
+    assert_eq!(
+        clearance(&airport, &stand(), &holding_point, &["27"]),
+        "Taxi to holding point A runway 27 via B, A"
+    );
+}
+
+#[test]
+fn holds_short_of_runways_on_the_way() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    // Past the holding point, on the runway exit
+    let exit = RouteLocation::Point(Coord { x: 0.0052, y: 0.0003 });
+
+    assert_eq!(
+        clearance(&airport, &stand(), &exit, &[]),
+        "Taxi via B, A, hold short of runway 09"
+    );
+    assert_eq!(
+        clearance(&airport, &stand(), &RouteLocation::RunwayExit(5), &[]),
+        "Taxi to runway 09 via B, A"
+    );
+}
+
+#[test]
+fn infers_unknown_holding_point_targets() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["taxiwayholdingposition"]["features"][0]["properties"]["idp"] = json!("$UNK");
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+
+    assert!(airport.taxiway_holding_positions[0].holding_point_target.is_none());
+    assert_eq!(
+        clearance(&airport, &stand(), &RouteLocation::HoldingPosition(18), &[]),
+        "Taxi to holding point A runway 09 via B, A"
+    );
+}
@@ -0,0 +1,143 @@
+use amdb_geo::{layer::Layer, parse_airport, serialize_airport, surface::SurfacePosition, taxi_route::RouteLocation};
+use geo::{Area, Contains, Coord};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// In the middle of the apron, where the hole is cut
+const IN_HOLE: Coord = Coord { x: 0.015, y: 0.0015 };
+
+#[test]
+fn keeps_polygon_holes() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["apronelement"]["features"][0]["geometry"]["coordinates"] = json!([
+        [
+            [0.013, 0.0012],
+            [0.013, 0.00315],
+            [0.017, 0.00315],
+            [0.017, 0.0012],
+            [0.013, 0.0012]
+        ],
+        [
+            [0.014, 0.0014],
+            [0.016, 0.0014],
+            [0.016, 0.0016],
+            [0.014, 0.0016],
+            [0.014, 0.0014]
+        ]
+    ]);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+    let apron = &airport.apron_elements[0].geometry;
+
+    assert_eq!(apron.0.len(), 1);
+    assert_eq!(apron.0[0].interiors().len(), 1);
+    assert!((apron.unsigned_area() - (0.004 * 0.00195 - 0.002 * 0.0002)).abs() < 1e-12);
+
+    assert!(!apron.contains(&IN_HOLE));
+    assert!(airport
+        .spatial_index()
+        .at_point(IN_HOLE, &[Layer::ApronElement])
+        .is_empty());
+    assert_eq!(airport.classify_position(IN_HOLE, None), SurfacePosition::OffPavement);
+
+    let beside_hole = Coord { x: 0.015, y: 0.0013 };
+
+    assert_eq!(
+        airport.classify_position(beside_hole, None),
+        SurfacePosition::Apron(Some(String::from("A1")))
+    );
+}
+
+#[test]
+fn keeps_every_part_of_multi_part_geometries() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    // Taxiway K split in two halfway along, both its guidance line and its pavement, with taxiway B closed
+    document["taxiwayguidanceline"]["features"][3]["geometry"] = json!({
+        "type": "MultiLineString",
+        "coordinates": [[[0.0052, 0.006], [0.01, 0.006]], [[0.01, 0.006], [0.015, 0.006]]]
+    });
+    document["taxiwayelement"]["features"][2]["geometry"] = json!({
+        "type": "MultiPolygon",
+        "coordinates": [
+            [[[0.0054, 0.0058], [0.0054, 0.0062], [0.01, 0.0062], [0.01, 0.0058], [0.0054, 0.0058]]],
+            [[[0.01, 0.0058], [0.01, 0.0062], [0.0148, 0.0062], [0.0148, 0.0058], [0.01, 0.0058]]]
+        ]
+    });
+    document["taxiwayguidanceline"]["features"][2]["properties"]["status"] = json!(0);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+
+    assert_eq!(airport.taxiway_guidance_lines[3].geometry.0.len(), 2);
+    assert_eq!(airport.taxiway_elements[2].geometry.0.len(), 2);
+    assert_eq!(
+        airport.classify_position(Coord { x: 0.012, y: 0.006 }, None),
+        SurfacePosition::Taxiway(Some(String::from("K")))
+    );
+
+    let graph = airport.taxi_graph();
+    let route = graph
+        .route(
+            &RouteLocation::Stand(String::from("214")),
+            &RouteLocation::HoldingPosition(18),
+        )
+        .unwrap();
+
+    assert_eq!(route.taxiway_ids, ["E", "K", "A"]);
+}
+
+#[test]
+fn rejects_geometry_of_the_wrong_type() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["taxiwayguidanceline"]["features"][3]["geometry"]["type"] = json!("Polygon");
+
+    assert!(parse_airport(&document.to_string()).is_err());
+
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["taxiwayelement"]["features"][2]["geometry"]["type"] = json!("LineString");
+
+    assert!(parse_airport(&document.to_string()).is_err());
+}
+
+#[test]
+fn keeps_vertex_elevations() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    document["runwaythreshold"]["features"][0]["geometry"]["coordinates"] = json!([0.0, 0.0, 12.5]);
+    document["taxiwayguidanceline"]["features"][0]["geometry"]["coordinates"] =
+        json!([[0.0052, 0.0004, 11.0], [0.0052, 0.0032, 13.0]]);
+    document["taxiwayelement"]["features"][1]["geometry"]["coordinates"] = json!([[
+        [0.0054, 0.00315, 13.0],
+        [0.0054, 0.00325, 13.0],
+        [0.0148, 0.00325, 14.0],
+        [0.0148, 0.00315, 14.0],
+        [0.0054, 0.00315, 13.0]
+    ]]);
+    // Elevations given for only some of the vertices are dropped
+    document["taxiwayguidanceline"]["features"][1]["geometry"]["coordinates"] =
+        json!([[0.0052, 0.0032, 13.0], [0.0052, 0.006]]);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+
+    assert_eq!(airport.runway_thresholds[0].location_elevation, Some(12.5));
+    assert_eq!(airport.runway_thresholds[1].location_elevation, None);
+    assert_eq!(
+        airport.taxiway_guidance_lines[0].vertex_elevations,
+        Some(vec![vec![11.0, 13.0]])
+    );
+    assert_eq!(
+        airport.taxiway_elements[1].vertex_elevations,
+        Some(vec![vec![vec![13.0, 13.0, 14.0, 14.0, 13.0]]])
+    );
+    assert_eq!(airport.taxiway_guidance_lines[1].vertex_elevations, None);
+    assert_eq!(airport.taxiway_guidance_lines[2].vertex_elevations, None);
+
+    // And written back out with their vertices
+    let written: Value = serde_json::from_str(&serialize_airport(&airport).unwrap()).unwrap();
+
+    assert_eq!(
+        written["taxiwayguidanceline"]["features"][0]["geometry"]["coordinates"],
+        json!([[0.0052, 0.0004, 11.0], [0.0052, 0.0032, 13.0]])
+    );
+}
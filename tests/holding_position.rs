@@ -0,0 +1,48 @@
+use amdb_geo::{output_types::HoldingPointTarget, parse_airport};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+#[test]
+fn infers_the_runway_a_holding_position_protects() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let targets = airport.infer_holding_targets();
+
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].holding_position_id, 18);
+    assert_eq!(
+        targets[0].inferred.as_ref().map(ToString::to_string).as_deref(),
+        Some("09.27")
+    );
+    assert!(!targets[0].disagrees());
+}
+
+#[test]
+fn infers_targets_the_data_leaves_unknown_or_gets_wrong() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    let holding_positions = document["taxiwayholdingposition"]["features"].as_array_mut().unwrap();
+
+    holding_positions[0]["properties"]["idp"] = json!("$UNK");
+
+    // Across taxiway B, a third of a kilometre short of taxiway E and far from the runway
+    let mut holding_position = holding_positions[0].clone();
+    holding_position["geometry"]["coordinates"] = json!([[0.012, 0.00315], [0.012, 0.00325]]);
+    holding_position["properties"]["id"] = json!(20);
+    holding_position["properties"]["idlin"] = json!("B");
+    holding_position["properties"]["idp"] = json!("09.27");
+    holding_positions.push(holding_position);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+    let targets = airport.infer_holding_targets();
+
+    assert_eq!(targets[0].supplied, None);
+    assert_eq!(targets[0].target().map(ToString::to_string).as_deref(), Some("09.27"));
+    assert!(!targets[0].disagrees());
+
+    assert_eq!(
+        targets[1].inferred,
+        Some(HoldingPointTarget::Taxiway(String::from("E")))
+    );
+    assert!(targets[1].disagrees());
+}
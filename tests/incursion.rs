@@ -0,0 +1,66 @@
+use amdb_geo::{incursion::PassedHoldingPosition, parse_airport};
+use geo::{Coord, LineString};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// Down taxiway A past holding position 18 and straight across runway 09.27
+fn across_runway_on_a() -> LineString { LineString::from(vec![(0.0052, 0.003), (0.0052, 0.0012), (0.0052, -0.001)]) }
+
+#[test]
+fn crosses_the_runway_past_its_holding_position() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let crossings = airport.runway_crossings(&across_runway_on_a());
+
+    assert_eq!(crossings.len(), 1);
+
+    let crossing = &crossings[0];
+
+    assert_eq!(crossing.runway_id.to_string(), "09.27");
+    assert!(crossing.entry.is_some_and(|entry| (entry.y - 0.0002).abs() < 1e-9));
+    assert!(crossing.exit.is_some_and(|exit| (exit.y + 0.0002).abs() < 1e-9));
+    assert_eq!(
+        crossing.holding_position,
+        Some(PassedHoldingPosition {
+            id: 18,
+            targets_runway: true
+        })
+    );
+    assert!(!crossing.is_incursion());
+}
+
+#[test]
+fn entering_without_holding_is_an_incursion() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    // Across the grass beside taxiway A, missing its holding position
+    let path = LineString::from(vec![(0.004, 0.003), (0.004, -0.001)]);
+    let crossings = airport.runway_crossings(&path);
+
+    assert_eq!(crossings.len(), 1);
+    assert_eq!(crossings[0].holding_position, None);
+    assert!(crossings[0].is_incursion());
+
+    // Leaving the runway from where the path starts on it is not
+    let path = LineString::from(vec![Coord { x: 0.0052, y: 0.0 }, Coord { x: 0.0052, y: 0.003 }]);
+    let crossings = airport.runway_crossings(&path);
+
+    assert_eq!(crossings[0].entry, None);
+    assert!(!crossings[0].is_incursion());
+}
+
+#[test]
+fn infers_unknown_holding_position_targets() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["taxiwayholdingposition"]["features"][0]["properties"]["idp"] = json!("$UNK");
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+    let crossings = airport.runway_crossings(&across_runway_on_a());
+
+    assert!(crossings[0]
+        .holding_position
+        .as_ref()
+        .is_some_and(|holding_position| holding_position.targets_runway));
+    assert!(!crossings[0].is_incursion());
+}
@@ -0,0 +1,68 @@
+use amdb_geo::{error::ParseError, layer::Layer, parse_airport, parse_airport_lenient};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// The fixture with a bad runway threshold and a bad taxiway guidance line
+fn damaged() -> String {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["runwaythreshold"]["features"][0]["properties"]["brngtrue"] = json!("east");
+    document["taxiwayguidanceline"]["features"][2]["properties"]["status"] = json!("open");
+
+    document.to_string()
+}
+
+#[test]
+fn skips_bad_features_and_keeps_the_rest() {
+    let (airport, warnings) = parse_airport_lenient(&damaged()).unwrap();
+
+    assert_eq!(warnings.len(), 2);
+
+    assert_eq!(warnings[0].layer, Layer::RunwayThreshold);
+    assert_eq!(warnings[0].index, 0);
+    assert_eq!(warnings[0].id, Some(6));
+    assert_eq!(warnings[0].property.as_deref(), Some("properties.brngtrue"));
+
+    assert_eq!(warnings[1].layer, Layer::TaxiwayGuidanceLine);
+    assert_eq!(warnings[1].index, 2);
+    assert_eq!(warnings[1].id, Some(15));
+    assert_eq!(warnings[1].property.as_deref(), Some("properties.status"));
+
+    let threshold_ids: Vec<_> = airport.runway_thresholds.iter().map(|threshold| threshold.id).collect();
+    assert_eq!(threshold_ids, [7]);
+
+    let guidance_line_ids: Vec<_> = airport.taxiway_guidance_lines.iter().map(|line| line.id).collect();
+    assert_eq!(guidance_line_ids, [13, 14, 16, 17]);
+
+    assert_eq!(airport.runway_elements.len(), 1);
+    assert!(airport
+        .taxiway("B")
+        .is_some_and(|taxiway| taxiway.guidance_line_ids.is_empty()));
+}
+
+#[test]
+fn strict_parse_fails_on_the_first_bad_feature() {
+    match parse_airport(&damaged()) {
+        Err(ParseError::Feature(e)) => assert_eq!((e.layer, e.id), (Layer::RunwayThreshold, Some(6))),
+        Err(e) => panic!("expected a feature error, got {e}"),
+        Ok(_) => panic!("expected a feature error"),
+    }
+}
+
+#[test]
+fn clean_document_has_no_warnings() {
+    let (_, warnings) = parse_airport_lenient(AIRPORT).unwrap();
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn document_errors_are_not_skipped() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document.as_object_mut().unwrap().remove("aerodromereferencepoint");
+
+    assert!(matches!(
+        parse_airport_lenient(&document.to_string()),
+        Err(ParseError::MissingLayer(Layer::AerodromeReferencePoint))
+    ));
+}
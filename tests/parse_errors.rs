@@ -0,0 +1,103 @@
+use amdb_geo::{
+    error::{FeatureError, ParseError},
+    layer::Layer,
+    parse_airport,
+};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+fn fixture() -> Value { serde_json::from_str(AIRPORT).unwrap() }
+
+fn feature_error(document: &Value) -> FeatureError {
+    match parse_airport(&document.to_string()) {
+        Err(ParseError::Feature(e)) => e,
+        Err(e) => panic!("expected a feature error, got {e}"),
+        Ok(_) => panic!("expected a feature error"),
+    }
+}
+
+#[test]
+fn parses_fixture() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    assert_eq!(airport.aerodrome_reference_point.airport_id, "XTST");
+    assert_eq!(airport.runway_elements.len(), 1);
+    assert_eq!(airport.taxiway_guidance_lines.len(), 5);
+}
+
+#[test]
+fn invalid_property_reports_feature_and_property() {
+    let mut document = fixture();
+    document["runwayelement"]["features"][0]["properties"]["idrwy"] = json!("09-27");
+
+    let e = feature_error(&document);
+
+    assert_eq!(e.layer, Layer::RunwayElement);
+    assert_eq!(e.index, 0);
+    assert_eq!(e.id, Some(4));
+    assert_eq!(e.property.as_deref(), Some("properties.idrwy"));
+    assert_eq!(
+        e.to_string(),
+        "runwayelement feature 0 (id 4): properties.idrwy: invalid runway identifier \"09-27\""
+    );
+}
+
+#[test]
+fn malformed_geometry_reports_path() {
+    let mut document = fixture();
+    document["runwaythreshold"]["features"][1]["geometry"]["coordinates"] = json!([0.03]);
+
+    let e = feature_error(&document);
+
+    assert_eq!(e.layer, Layer::RunwayThreshold);
+    assert_eq!(e.index, 1);
+    assert_eq!(e.id, Some(7));
+    assert!(e
+        .property
+        .as_deref()
+        .is_some_and(|property| property.starts_with("geometry")));
+}
+
+#[test]
+fn missing_property_reports_path() {
+    let mut document = fixture();
+    document["taxiwayholdingposition"]["features"][0]["properties"]
+        .as_object_mut()
+        .unwrap()
+        .remove("catstop");
+
+    let e = feature_error(&document);
+
+    assert_eq!(e.layer, Layer::TaxiwayHoldingPosition);
+    assert_eq!(e.id, Some(18));
+    assert!(e.reason.contains("catstop"));
+}
+
+#[test]
+fn missing_reference_point_is_an_error() {
+    let mut document = fixture();
+    document.as_object_mut().unwrap().remove("aerodromereferencepoint");
+
+    assert!(matches!(
+        parse_airport(&document.to_string()),
+        Err(ParseError::MissingLayer(Layer::AerodromeReferencePoint))
+    ));
+
+    let mut document = fixture();
+    document["aerodromereferencepoint"]["features"] = json!([]);
+
+    assert!(matches!(
+        parse_airport(&document.to_string()),
+        Err(ParseError::EmptyLayer(Layer::AerodromeReferencePoint))
+    ));
+}
+
+#[test]
+fn invalid_json_is_an_error() {
+    assert!(matches!(
+        parse_airport("{\"runwayelement\": "),
+        Err(ParseError::Json(_))
+    ));
+    assert!(matches!(parse_airport("[]"), Err(ParseError::Json(_))));
+}
@@ -0,0 +1,85 @@
+use std::io::{self, Read};
+
+use amdb_geo::{
+    error::ParseError,
+    parse_airport,
+    parse_airport_from_reader,
+    parse_airport_lenient,
+    parse_airport_lenient_from_reader,
+    serialize_airport,
+};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// A reader which hands out a few bytes at a time, as a slow stream would
+struct Trickle<'a> {
+    data: &'a [u8],
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = buf.len().min(self.data.len()).min(7);
+        buf[..count].copy_from_slice(&self.data[..count]);
+        self.data = &self.data[count..];
+
+        Ok(count)
+    }
+}
+
+#[test]
+fn reader_matches_str() {
+    let from_str = parse_airport(AIRPORT).unwrap();
+    let from_reader = parse_airport_from_reader(AIRPORT.as_bytes()).unwrap();
+
+    assert_eq!(
+        serialize_airport(&from_str).unwrap(),
+        serialize_airport(&from_reader).unwrap()
+    );
+    assert_eq!(from_str.present_layers, from_reader.present_layers);
+    assert_eq!(from_str.runways().len(), from_reader.runways().len());
+    assert_eq!(from_str.taxiways().len(), from_reader.taxiways().len());
+    assert_eq!(from_str.stands().len(), from_reader.stands().len());
+}
+
+#[test]
+fn reader_handles_partial_reads() {
+    let from_str = parse_airport(AIRPORT).unwrap();
+    let from_reader = parse_airport_from_reader(Trickle {
+        data: AIRPORT.as_bytes(),
+    })
+    .unwrap();
+
+    assert_eq!(
+        serialize_airport(&from_str).unwrap(),
+        serialize_airport(&from_reader).unwrap()
+    );
+}
+
+#[test]
+fn lenient_reader_matches_str() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["runwayexitline"]["features"][0]["properties"]["direc"] = json!(null);
+    let document = document.to_string();
+
+    let (from_str, str_warnings) = parse_airport_lenient(&document).unwrap();
+    let (from_reader, reader_warnings) = parse_airport_lenient_from_reader(document.as_bytes()).unwrap();
+
+    assert_eq!(str_warnings.len(), 1);
+    assert_eq!(str_warnings, reader_warnings);
+    assert_eq!(
+        serialize_airport(&from_str).unwrap(),
+        serialize_airport(&from_reader).unwrap()
+    );
+}
+
+#[test]
+fn reader_reports_trailing_data() {
+    let document = format!("{AIRPORT} {{}}");
+
+    assert!(matches!(parse_airport(&document), Err(ParseError::Json(_))));
+    assert!(matches!(
+        parse_airport_from_reader(document.as_bytes()),
+        Err(ParseError::Json(_))
+    ));
+}
@@ -0,0 +1,216 @@
+use amdb_geo::{
+    output_types::{RunwayDesignator, RunwayId, RunwaySide},
+    parse_airport,
+    runway::RunwayIssue,
+};
+use geo::{Area, Coord};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// The fixture with its runway split into two elements, the eastern one naming the runway from its other end and
+/// starting `gap` degrees east of where the western one ends
+fn split_runway(gap: f64) -> String {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    let elements = document["runwayelement"]["features"].as_array_mut().unwrap();
+
+    let mut east = elements[0].clone();
+    east["geometry"]["coordinates"] = json!([[
+        [0.015 + gap, -0.0002],
+        [0.03, -0.0002],
+        [0.03, 0.0002],
+        [0.015 + gap, 0.0002],
+        [0.015 + gap, -0.0002]
+    ]]);
+    east["properties"]["id"] = json!(40);
+    east["properties"]["idrwy"] = json!("27.09");
+
+    elements[0]["geometry"]["coordinates"] = json!([[
+        [0.0, -0.0002],
+        [0.015, -0.0002],
+        [0.015, 0.0002],
+        [0.0, 0.0002],
+        [0.0, -0.0002]
+    ]]);
+    elements.push(east);
+
+    document.to_string()
+}
+
+#[test]
+fn joins_elements_naming_the_runway_from_either_end() {
+    let airport = parse_airport(&split_runway(0.0)).unwrap();
+
+    assert_eq!(airport.runways().len(), 1);
+
+    let runway = &airport.runways()[0];
+
+    assert_eq!(runway.runway_id.to_string(), "09.27");
+    assert_eq!(runway.element_ids, [4, 40]);
+    assert_eq!(runway.ends[0].designator, RunwayDesignator::try_from("09").unwrap());
+    assert_eq!(runway.ends[0].threshold, Some(6));
+    assert_eq!(runway.ends[1].threshold, Some(7));
+    assert!((runway.length - 3335.8).abs() < 1.0, "{} m", runway.length);
+    assert!((runway.width - 44.5).abs() < 1.0, "{} m", runway.width);
+
+    let centerline = runway.centerline.unwrap();
+    assert!(centerline.start.x < centerline.end.x);
+
+    assert!(airport.runway("27.09").is_some());
+    assert!(airport.runway("27").is_some());
+}
+
+#[test]
+fn dissolves_elements_which_nearly_meet() {
+    let airport = parse_airport(&split_runway(3e-8)).unwrap();
+    let runway = &airport.runways()[0];
+
+    assert_eq!(runway.surface(&airport).0.len(), 2);
+
+    let outline = runway.outline(&airport);
+
+    assert_eq!(outline.0.len(), 1);
+    assert!(outline.0[0].interiors().is_empty());
+    assert!((outline.unsigned_area() - 0.03 * 0.0004).abs() < 1e-12);
+}
+
+fn designator(s: &str) -> RunwayDesignator { RunwayDesignator::try_from(s).unwrap() }
+
+fn runway_id(s: &str) -> RunwayId { RunwayId::try_from(s.to_string()).unwrap() }
+
+#[test]
+fn parses_runway_designators() {
+    assert_eq!(
+        designator("09L"),
+        RunwayDesignator::Numbered {
+            number: 9,
+            side: Some(RunwaySide::Left)
+        }
+    );
+    assert_eq!(designator("36"), RunwayDesignator::Numbered { number: 36, side: None });
+    assert_eq!(
+        designator("18W"),
+        RunwayDesignator::Numbered {
+            number: 18,
+            side: Some(RunwaySide::Water)
+        }
+    );
+    assert_eq!(designator("H1"), RunwayDesignator::Named(String::from("H1")));
+
+    for invalid in ["", "00", "37", "009", "09X", "09-"] {
+        assert!(RunwayDesignator::try_from(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn finds_reciprocal_designators() {
+    assert_eq!(designator("09L").reciprocal(), Some(designator("27R")));
+    assert_eq!(designator("18").reciprocal(), Some(designator("36")));
+    assert_eq!(designator("36").reciprocal(), Some(designator("18")));
+    assert_eq!(designator("01C").reciprocal(), Some(designator("19C")));
+    assert_eq!(designator("H1").reciprocal(), None);
+}
+
+#[test]
+fn parses_runway_ids() {
+    assert!(runway_id("09.27").is_reciprocal());
+    assert!(runway_id("18.36").is_reciprocal());
+    assert!(!runway_id("09.28").is_reciprocal());
+    assert!(runway_id("H1.H2").is_reciprocal());
+
+    assert_eq!(runway_id("9.27"), runway_id("09.27"));
+    assert_eq!(runway_id("9.27").to_string(), "9.27");
+    assert!(runway_id("27.09").same_runway(&runway_id("09.27")));
+    assert_ne!(runway_id("27.09"), runway_id("09.27"));
+
+    for invalid in ["09", "09.27.36", "00.18", "09.37", "09.27X"] {
+        assert!(RunwayId::try_from(invalid.to_string()).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn reports_runway_issues() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    assert!(airport.runways()[0].issues.is_empty());
+
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["runwayelement"]["features"][0]["properties"]["idrwy"] = json!("09.28");
+    document["runwaythreshold"]["features"][1]["properties"]["idthr"] = json!("28");
+    document["runwaythreshold"]["features"][1]["properties"]["brngmag"] = json!(250.0);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+
+    assert_eq!(
+        airport.runways()[0].issues,
+        [
+            RunwayIssue::NonReciprocalEnds,
+            RunwayIssue::BearingMismatch { threshold: 7 }
+        ]
+    );
+}
+
+/// The fixture with the threshold of runway 09 displaced 0.003 degrees, about 334 metres, down the runway
+fn displaced_threshold() -> String {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    let threshold = &mut document["runwaythreshold"]["features"][0];
+    threshold["geometry"]["coordinates"] = json!([0.003, 0.0]);
+    threshold["properties"]["lda"] = json!(3002.0);
+
+    document.to_string()
+}
+
+#[test]
+fn measures_declared_distances_from_a_point() {
+    let airport = parse_airport(&displaced_threshold()).unwrap();
+    let threshold = &airport.runway_thresholds[0];
+    let exit_a = Coord { x: 0.0052, y: 0.0 };
+    let metres = |degrees: f64| degrees * 111_195.08;
+
+    // The takeoff run counts from the runway end, behind the displaced threshold
+    let takeoff_run = threshold.takeoff_run_available_from(Coord { x: 0.0, y: 0.0 }, exit_a);
+    assert!((takeoff_run - (3336.0 - metres(0.0052))).abs() < 1.0, "{takeoff_run} m");
+
+    // The landing distance counts from the threshold itself
+    let landing_distance = threshold.landing_distance_available_from(exit_a);
+    assert!(
+        (landing_distance - (3002.0 - metres(0.0022))).abs() < 1.0,
+        "{landing_distance} m"
+    );
+
+    // Points behind where the distances start get them in full, and points beyond their end get nothing
+    assert_eq!(
+        threshold.landing_distance_available_from(Coord { x: 0.001, y: 0.0 }),
+        3002.0
+    );
+    assert_eq!(
+        threshold.takeoff_run_available_from(Coord { x: 0.0, y: 0.0 }, Coord { x: -0.001, y: 0.0 }),
+        3336.0
+    );
+    assert_eq!(
+        threshold.landing_distance_available_from(Coord { x: 0.04, y: 0.0 }),
+        0.0
+    );
+}
+
+#[test]
+fn measures_the_takeoff_run_from_either_runway_end() {
+    let airport = parse_airport(&displaced_threshold()).unwrap();
+    let runway = &airport.runways()[0];
+    let exit_a = Coord { x: 0.0052, y: 0.0 };
+
+    let from_09 = runway
+        .takeoff_run_available_from(&airport, &designator("09"), exit_a)
+        .unwrap();
+    let from_27 = runway
+        .takeoff_run_available_from(&airport, &designator("27"), exit_a)
+        .unwrap();
+
+    // Runway 09 starts at the runway end rather than its displaced threshold
+    assert!((from_09 - 2757.8).abs() < 1.0, "{from_09} m");
+    assert!((from_27 - 578.2).abs() < 1.0, "{from_27} m");
+    assert_eq!(
+        runway.takeoff_run_available_from(&airport, &designator("18"), exit_a),
+        None
+    );
+}
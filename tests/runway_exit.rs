@@ -0,0 +1,45 @@
+use amdb_geo::{parse_airport, runway_exit::ExitKind};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+#[test]
+fn tables_exit_a_for_both_landing_directions() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let tables = airport.runway_exit_tables();
+    let directions: Vec<_> = tables.iter().map(|table| table.landing_direction.to_string()).collect();
+
+    assert_eq!(directions, ["09", "27"]);
+
+    for (table, threshold_id, distance) in [(&tables[0], 6, 578.2), (&tables[1], 7, 2757.6)] {
+        assert_eq!(table.runway_id.to_string(), "09.27");
+        assert_eq!(table.threshold_id, Some(threshold_id));
+        assert_eq!(table.exits.len(), 1);
+
+        let exit = &table.exits[0];
+
+        assert_eq!(exit.exit_line_id, 5);
+        assert_eq!(exit.taxiway_id.as_deref(), Some("A"));
+        assert_eq!(exit.kind, ExitKind::RightAngle);
+        assert!((exit.angle - 90.0).abs() < 1.0, "{}", exit.angle);
+        assert!(
+            (exit.distance_from_threshold - distance).abs() < 1.0,
+            "{} m",
+            exit.distance_from_threshold
+        );
+        assert!(exit.usable);
+    }
+}
+
+#[test]
+fn exits_beyond_the_landing_distance_are_unusable() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["runwaythreshold"]["features"][1]["properties"]["lda"] = json!(2000.0);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+    let tables = airport.runway_exit_tables();
+
+    assert!(tables[0].exits[0].usable);
+    assert!(!tables[1].exits[0].usable);
+}
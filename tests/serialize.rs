@@ -0,0 +1,63 @@
+use amdb_geo::{parse_airport, serialize_airport, serialize_airport_to_writer};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+fn round_trip(document: &str) -> Value {
+    let airport = parse_airport(document).unwrap();
+
+    serde_json::from_str(&serialize_airport(&airport).unwrap()).unwrap()
+}
+
+#[test]
+fn round_trip_reproduces_source() {
+    let source: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    assert_eq!(round_trip(AIRPORT), source);
+}
+
+#[test]
+fn round_trip_is_stable() {
+    let once = round_trip(AIRPORT);
+    let twice = round_trip(&once.to_string());
+
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn writer_matches_string() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let mut written = Vec::new();
+    serialize_airport_to_writer(&airport, &mut written).unwrap();
+
+    assert_eq!(
+        String::from_utf8(written).unwrap(),
+        serialize_airport(&airport).unwrap()
+    );
+}
+
+#[test]
+fn unknown_strings_are_written_as_unk() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    assert_eq!(airport.taxiway_elements[0].apron_id, None);
+
+    let written = round_trip(AIRPORT);
+    assert_eq!(
+        written["taxiwayelement"]["features"][0]["properties"]["idapron"],
+        json!("$UNK")
+    );
+}
+
+#[test]
+fn runway_ids_are_written_as_given() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["runwayelement"]["features"][0]["properties"]["idrwy"] = json!("9.27");
+
+    let written = round_trip(&document.to_string());
+
+    assert_eq!(
+        written["runwayelement"]["features"][0]["properties"]["idrwy"],
+        json!("9.27")
+    );
+}
@@ -0,0 +1,88 @@
+use amdb_geo::{
+    layer::Layer,
+    output_types::{AmdbElement, AnyFeature},
+    parse_airport,
+};
+use geo::Coord;
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// Where taxiway B leaves taxiway A
+const JUNCTION: Coord = Coord { x: 0.0052, y: 0.0032 };
+
+fn ids(features: &[AnyFeature]) -> Vec<u64> {
+    let mut ids: Vec<_> = features.iter().map(AmdbElement::id).collect();
+    ids.sort_unstable();
+
+    ids
+}
+
+#[test]
+fn finds_features_at_a_point() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let index = airport.spatial_index();
+
+    // Taxiway element A and the three guidance lines which meet on it
+    assert_eq!(ids(&index.at_point(JUNCTION, &[])), [9, 13, 14, 15]);
+    assert_eq!(
+        ids(&index.at_point(JUNCTION, &[Layer::TaxiwayGuidanceLine])),
+        [13, 14, 15]
+    );
+    assert_eq!(
+        ids(&index.at_point(JUNCTION, &[Layer::TaxiwayElement, Layer::ApronElement])),
+        [9]
+    );
+    assert!(index.at_point(JUNCTION, &[Layer::RunwayElement]).is_empty());
+}
+
+#[test]
+fn finds_the_nearest_features() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let index = airport.spatial_index();
+
+    let nearest = index.nearest(Coord { x: 0.001, y: 0.0 }, 2, &[Layer::RunwayThreshold]);
+    let distances: Vec<_> = nearest.iter().map(|(_, distance)| distance.round()).collect();
+
+    assert_eq!(
+        nearest.iter().map(|(feature, _)| feature.id()).collect::<Vec<_>>(),
+        [6, 7]
+    );
+    assert_eq!(distances, [111.0, 3225.0]);
+
+    // Matching every layer, the runway element under the point is nearer than either threshold
+    let nearest = index.nearest(Coord { x: 0.001, y: 0.0 }, 1, &[]);
+
+    assert_eq!(nearest[0].0.layer(), Layer::RunwayElement);
+    assert_eq!(nearest[0].1, 0.0);
+}
+
+#[test]
+fn finds_features_within_a_distance() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let index = airport.spatial_index();
+    let holding_point = Coord { x: 0.0052, y: 0.0012 };
+
+    let holding_positions = index.within_distance(holding_point, 5.0, &[Layer::TaxiwayHoldingPosition]);
+
+    assert_eq!(holding_positions.len(), 1);
+    assert_eq!(holding_positions[0].0.id(), 18);
+
+    // The edge of the runway lies 0.001 degrees, about 111 metres, south of the holding position
+    assert!(index
+        .within_distance(holding_point, 100.0, &[Layer::RunwayElement])
+        .is_empty());
+
+    let runways = index.within_distance(holding_point, 120.0, &[Layer::RunwayElement]);
+
+    assert_eq!(runways.len(), 1);
+    assert!((runways[0].1 - 111.2).abs() < 0.1, "{} m", runways[0].1);
+
+    let distances: Vec<_> = index
+        .within_distance(holding_point, 120.0, &[])
+        .iter()
+        .map(|(_, distance)| *distance)
+        .collect();
+
+    assert!(distances.len() > runways.len());
+    assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+}
@@ -0,0 +1,93 @@
+use amdb_geo::{
+    output_types::{RunwayDesignator, RunwayId},
+    parse_airport,
+    surface::SurfacePosition,
+    Airport,
+};
+use geo::Coord;
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// The fixture with taxiway A reaching onto the runway, and a stand area for stand 214 reaching onto taxiway E
+fn with_overlaps() -> Airport {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    document["taxiwayelement"]["features"][0]["geometry"]["coordinates"] = json!([[
+        [0.005, -0.0001],
+        [0.005, 0.0062],
+        [0.0054, 0.0062],
+        [0.0054, -0.0001],
+        [0.005, -0.0001]
+    ]]);
+
+    document["parkingstandarea"] = json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [[
+                    [0.0146, 0.002],
+                    [0.0146, 0.0034],
+                    [0.0156, 0.0034],
+                    [0.0156, 0.002],
+                    [0.0146, 0.002]
+                ]]
+            },
+            "properties": {
+                "id": 30,
+                "idstd": "214",
+                "gsurftyp": 1,
+                "idapron": "A1",
+                "jetway": 0,
+                "fuel": "",
+                "towing": 0,
+                "gndpower": 0
+            }
+        }]
+    });
+
+    parse_airport(&document.to_string()).unwrap()
+}
+
+fn classify(airport: &Airport, x: f64, y: f64) -> String { airport.classify_position(Coord { x, y }, None).to_string() }
+
+#[test]
+fn prefers_runways_then_taxiways_then_stands_then_aprons() {
+    let airport = with_overlaps();
+
+    // Runway and taxiway A
+    assert_eq!(classify(&airport, 0.0052, -0.00005), "on runway 09.27");
+    // Taxiway A alone
+    assert_eq!(classify(&airport, 0.0052, 0.0003), "on taxiway A");
+    // Taxiway E, the stand area and the apron
+    assert_eq!(classify(&airport, 0.015, 0.0033), "on taxiway E");
+    // The stand area and the apron
+    assert_eq!(classify(&airport, 0.015, 0.0025), "on stand 214");
+    // The apron alone
+    assert_eq!(classify(&airport, 0.0165, 0.0015), "on apron A1");
+    assert_eq!(classify(&airport, 0.02, 0.0015), "off pavement");
+}
+
+#[test]
+fn gives_the_direction_along_a_runway() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let runway_id = RunwayId::try_from(String::from("09.27")).unwrap();
+    let location = Coord { x: 0.01, y: 0.0 };
+
+    assert_eq!(
+        airport.classify_position(location, Some(268.0)),
+        SurfacePosition::Runway {
+            runway_id: runway_id.clone(),
+            direction: Some(RunwayDesignator::try_from("27").unwrap()),
+        }
+    );
+    assert_eq!(
+        airport.classify_position(location, None),
+        SurfacePosition::Runway {
+            runway_id,
+            direction: None
+        }
+    );
+}
@@ -0,0 +1,140 @@
+use amdb_geo::{
+    parse_airport,
+    taxi_graph::{EdgeKind, TaxiGraph, DEFAULT_SNAP_TOLERANCE},
+    Airport,
+};
+use geo::Coord;
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// The fixture with a taxiway guidance line added for each taxiway designator and coordinates given
+fn with_guidance_lines(lines: &[(&str, Value)]) -> Airport {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    let features = document["taxiwayguidanceline"]["features"].as_array_mut().unwrap();
+
+    for (index, (taxiway_id, coordinates)) in lines.iter().enumerate() {
+        let mut feature = features[0].clone();
+        feature["geometry"]["coordinates"] = coordinates.clone();
+        feature["properties"]["id"] = json!(30 + index);
+        feature["properties"]["idlin"] = json!(taxiway_id);
+        features.push(feature);
+    }
+
+    parse_airport(&document.to_string()).unwrap()
+}
+
+/// The node at a location, allowing for rounding where lines are split
+fn node_at(graph: &TaxiGraph, location: Coord) -> usize {
+    let node = graph.nearest_node(location).unwrap();
+    let found = graph.node(node).location;
+
+    assert!(
+        (found.x - location.x).abs() < 1e-9 && (found.y - location.y).abs() < 1e-9,
+        "no node at {location:?}, nearest is {found:?}"
+    );
+
+    node
+}
+
+/// The taxiway of each edge meeting at a node, in order
+fn taxiways_at(graph: &TaxiGraph, node: usize) -> Vec<&str> {
+    let mut taxiway_ids: Vec<_> = graph
+        .edges_at(node)
+        .map(|(_, edge)| edge.taxiway_id.as_deref().unwrap_or("-"))
+        .collect();
+    taxiway_ids.sort_unstable();
+
+    taxiway_ids
+}
+
+#[test]
+fn builds_the_network_of_the_fixture() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    // The ends of the five taxiway guidance lines, the runway exit and stand guidance lines, the runway ends and the
+    // holding position on taxiway A
+    assert_eq!(graph.nodes().len(), 10);
+    // Taxiway A in three pieces and B, K and E whole, the exit, the stand line and the runway either side of the exit
+    assert_eq!(graph.edges().len(), 10);
+
+    let count = |kind: EdgeKind| graph.edges().iter().filter(|edge| edge.kind == kind).count();
+
+    assert_eq!(count(EdgeKind::Taxiway), 6);
+    assert_eq!(count(EdgeKind::RunwayExit), 1);
+    assert_eq!(count(EdgeKind::Stand), 1);
+    assert_eq!(count(EdgeKind::Runway), 2);
+
+    let junction = node_at(&graph, Coord { x: 0.0052, y: 0.0032 });
+
+    assert_eq!(taxiways_at(&graph, junction), ["A", "A", "B"]);
+}
+
+#[test]
+fn cuts_lines_at_holding_positions() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    let nodes: Vec<_> = graph.holding_position_nodes(18).collect();
+    let holding_point = node_at(&graph, Coord { x: 0.0052, y: 0.0012 });
+
+    assert_eq!(nodes, [holding_point]);
+
+    // Guidance line 13 runs on either side of the holding position
+    let pieces: Vec<_> = graph.edges_at(holding_point).map(|(_, edge)| edge.feature_id).collect();
+
+    assert_eq!(pieces, [Some(13), Some(13)]);
+}
+
+#[test]
+fn links_stands_to_the_end_of_their_lead_in_line() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    let node = graph.stand_node("214").unwrap();
+
+    assert_eq!(node, node_at(&graph, Coord { x: 0.015, y: 0.0022 }));
+    assert_eq!(graph.node(node).stand_ids, ["214"]);
+    assert!(graph.stand_node("999").is_none());
+}
+
+#[test]
+fn cuts_lines_where_they_cross_or_meet() {
+    let airport = with_guidance_lines(&[
+        // Crossing taxiway B
+        ("C", json!([[0.01, 0.0028], [0.01, 0.0036]])),
+        // Ending part way along taxiway B
+        ("D", json!([[0.008, 0.0045], [0.008, 0.0032]])),
+    ]);
+    let graph = airport.taxi_graph();
+
+    assert_eq!(graph.nodes().len(), 15);
+    assert_eq!(graph.edges().len(), 15);
+
+    let crossing = node_at(&graph, Coord { x: 0.01, y: 0.0032 });
+    let junction = node_at(&graph, Coord { x: 0.008, y: 0.0032 });
+
+    assert_eq!(taxiways_at(&graph, crossing), ["B", "B", "C", "C"]);
+    assert_eq!(taxiways_at(&graph, junction), ["B", "B", "D"]);
+}
+
+#[test]
+fn joins_line_ends_within_the_snap_tolerance() {
+    // Taxiway F stops half a metre short of where taxiway K meets taxiway E
+    let airport = with_guidance_lines(&[("F", json!([[0.02, 0.006], [0.0150045, 0.006]]))]);
+
+    let graph = TaxiGraph::new(&airport, DEFAULT_SNAP_TOLERANCE);
+    let corner = node_at(&graph, Coord { x: 0.015, y: 0.006 });
+
+    assert_eq!(graph.nodes().len(), 11);
+    assert_eq!(taxiways_at(&graph, corner), ["E", "F", "K"]);
+
+    let graph = TaxiGraph::new(&airport, 0.1);
+
+    assert_eq!(graph.nodes().len(), 12);
+    assert_eq!(
+        taxiways_at(&graph, node_at(&graph, Coord { x: 0.015, y: 0.006 })),
+        ["E", "K"]
+    );
+}
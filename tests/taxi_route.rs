@@ -0,0 +1,231 @@
+use amdb_geo::{
+    error::RouteError,
+    parse_airport,
+    taxi_graph::EdgeKind,
+    taxi_route::{AircraftProfile, Limitation, RouteLocation, TaxiRoute},
+    Airport,
+};
+use geo::Coord;
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+/// The metres in a degree of latitude, or of longitude at the equator where the fixture lies
+const METRES_PER_DEGREE: f64 = 111_195.08;
+
+const HOLDING_POINT: RouteLocation = RouteLocation::HoldingPosition(18);
+
+fn stand() -> RouteLocation { RouteLocation::Stand(String::from("214")) }
+
+/// A small aircraft, which fits on every taxiway of the fixture
+const SMALL: AircraftProfile = AircraftProfile {
+    wingspan: 12.0,
+    gear_span: 3.0,
+    wheelbase: 5.0,
+    turn_radius: 10.0,
+};
+
+/// A large aircraft, which needs more pavement than the narrow taxiway B has
+const LARGE: AircraftProfile = AircraftProfile {
+    wingspan: 40.0,
+    gear_span: 10.0,
+    wheelbase: 15.0,
+    turn_radius: 25.0,
+};
+
+/// The fixture with a property of one of its taxiway guidance lines changed, by feature id
+fn with_guidance_line(id: u64, property: &str, value: Value) -> Airport {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    for feature in document["taxiwayguidanceline"]["features"].as_array_mut().unwrap() {
+        if feature["properties"]["id"] == json!(id) {
+            feature["properties"][property] = value.clone();
+        }
+    }
+
+    parse_airport(&document.to_string()).unwrap()
+}
+
+/// Whether two positions are the same, allowing for rounding where lines are split
+fn near(a: Option<&Coord>, b: Coord) -> bool { a.is_some_and(|a| (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9) }
+
+/// The fixture with a runway exit C from the runway up to taxiway B, held short of the runway like taxiway A
+fn with_exit_c() -> Airport {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    let exit_lines = document["runwayexitline"]["features"].as_array_mut().unwrap();
+    let mut exit_line = exit_lines[0].clone();
+    exit_line["geometry"]["coordinates"] = json!([[0.012, 0.0], [0.012, 0.0032]]);
+    exit_line["properties"]["id"] = json!(19);
+    exit_line["properties"]["idlin"] = json!("C");
+    exit_lines.push(exit_line);
+
+    let holding_positions = document["taxiwayholdingposition"]["features"].as_array_mut().unwrap();
+    let mut holding_position = holding_positions[0].clone();
+    holding_position["geometry"]["coordinates"] = json!([[0.0118, 0.0012], [0.0122, 0.0012]]);
+    holding_position["properties"]["id"] = json!(20);
+    holding_position["properties"]["idlin"] = json!("C");
+    holding_positions.push(holding_position);
+
+    parse_airport(&document.to_string()).unwrap()
+}
+
+fn taxiways(route: &TaxiRoute) -> Vec<&str> { route.taxiway_ids.iter().map(String::as_str).collect() }
+
+#[test]
+fn routes_stand_to_holding_point() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    let route = graph.route(&stand(), &HOLDING_POINT).unwrap();
+
+    assert_eq!(taxiways(&route), ["B", "A"]);
+    assert!(near(route.geometry.0.first(), Coord { x: 0.015, y: 0.0022 }));
+    assert!(near(route.geometry.0.last(), Coord { x: 0.0052, y: 0.0012 }));
+    assert_eq!(route.nodes.len(), route.edges.len() + 1);
+
+    // Along the stand guidance line, taxiway B and taxiway A down to the holding position
+    let expected = (0.001 + 0.0098 + 0.002) * METRES_PER_DEGREE;
+    assert!((route.length - expected).abs() < 1.0, "{} m", route.length);
+}
+
+#[test]
+fn avoids_closed_lines() {
+    let airport = with_guidance_line(15, "status", json!(0));
+    let graph = airport.taxi_graph();
+
+    let route = graph.route(&stand(), &HOLDING_POINT).unwrap();
+
+    assert_eq!(taxiways(&route), ["E", "K", "A"]);
+}
+
+#[test]
+fn follows_one_way_lines_in_their_direction() {
+    // Taxiway B is drawn from taxiway A towards the stand, and may now only be followed that way
+    let airport = with_guidance_line(15, "direc", json!(1));
+    let graph = airport.taxi_graph();
+
+    let outbound = graph.route(&stand(), &HOLDING_POINT).unwrap();
+    let inbound = graph.route(&HOLDING_POINT, &stand()).unwrap();
+
+    assert_eq!(taxiways(&outbound), ["E", "K", "A"]);
+    assert_eq!(taxiways(&inbound), ["A", "B"]);
+}
+
+#[test]
+fn never_taxies_along_runways() {
+    let airport = with_exit_c();
+    let graph = airport.taxi_graph();
+
+    // Along the runway between the two exits is shorter than taxiing round by taxiway B
+    let route = graph
+        .route(&RouteLocation::HoldingPosition(20), &HOLDING_POINT)
+        .unwrap();
+
+    assert_eq!(taxiways(&route), ["C", "B", "A"]);
+    assert!(route
+        .edges
+        .iter()
+        .all(|&edge| graph.edge(edge).kind != EdgeKind::Runway));
+
+    // Leaving the runway is still possible from where an aircraft stopped on it
+    let on_runway = RouteLocation::Point(Coord { x: 0.01, y: 0.0 });
+    let route = graph.route(&on_runway, &RouteLocation::HoldingPosition(20)).unwrap();
+
+    assert_eq!(taxiways(&route), ["C"]);
+    assert_eq!(graph.edge(route.edges[0]).kind, EdgeKind::Runway);
+}
+
+#[test]
+fn joins_points_to_the_nearest_line() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    let from = RouteLocation::Point(Coord { x: 0.01, y: 0.0033 });
+    let route = graph.route(&from, &HOLDING_POINT).unwrap();
+
+    assert_eq!(taxiways(&route), ["B", "A"]);
+    assert!(near(route.geometry.0.first(), Coord { x: 0.01, y: 0.0032 }));
+}
+
+#[test]
+fn keeps_large_aircraft_off_narrow_taxiways() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    let small = graph.route_for_aircraft(&stand(), &HOLDING_POINT, &SMALL).unwrap();
+    let large = graph.route_for_aircraft(&stand(), &HOLDING_POINT, &LARGE).unwrap();
+
+    assert_eq!(taxiways(&small), ["B", "A"]);
+    assert_eq!(taxiways(&large), ["E", "K", "A"]);
+    assert!(large.length > small.length);
+}
+
+#[test]
+fn ignores_short_stubs_when_measuring_curves() {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+
+    // Taxiway K bends gently, by well under a degree, halfway along
+    document["taxiwayguidanceline"]["features"][3]["geometry"]["coordinates"] =
+        json!([[0.0052, 0.006], [0.01, 0.00597], [0.015, 0.006]]);
+
+    // A holding position crossing K a fraction of a metre before the bend leaves a stub of line there
+    let holding_positions = document["taxiwayholdingposition"]["features"].as_array_mut().unwrap();
+    let mut holding_position = holding_positions[0].clone();
+    holding_position["geometry"]["coordinates"] = json!([[0.0099973, 0.0058], [0.0099973, 0.0062]]);
+    holding_position["properties"]["id"] = json!(20);
+    holding_position["properties"]["idlin"] = json!("K");
+    holding_position["properties"]["idp"] = json!("K");
+    holding_positions.push(holding_position);
+
+    let airport = parse_airport(&document.to_string()).unwrap();
+    let graph = airport.taxi_graph();
+
+    let route = graph.route_for_aircraft(&stand(), &HOLDING_POINT, &LARGE).unwrap();
+
+    assert_eq!(taxiways(&route), ["E", "K", "A"]);
+}
+
+#[test]
+fn reports_the_limitation_when_no_route_fits() {
+    let airport = with_guidance_line(16, "status", json!(0));
+    let graph = airport.taxi_graph();
+
+    let Err(RouteError::Limited(Limitation::Width { edge, width, required })) =
+        graph.route_for_aircraft(&stand(), &HOLDING_POINT, &LARGE)
+    else {
+        panic!("expected a width limitation");
+    };
+
+    assert_eq!(graph.edge(edge).taxiway_id.as_deref(), Some("B"));
+    assert!(width < required);
+}
+
+#[test]
+fn reports_the_limitation_where_a_point_is_joined() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+
+    // The nearest line to the point is taxiway B, even though the aircraft cannot use it
+    let from = RouteLocation::Point(Coord { x: 0.01, y: 0.0033 });
+
+    let Err(RouteError::Limited(Limitation::Width { edge, .. })) =
+        graph.route_for_aircraft(&from, &HOLDING_POINT, &LARGE)
+    else {
+        panic!("expected a width limitation");
+    };
+
+    assert_eq!(graph.edge(edge).taxiway_id.as_deref(), Some("B"));
+}
+
+#[test]
+fn unknown_locations_are_errors() {
+    let airport = parse_airport(AIRPORT).unwrap();
+    let graph = airport.taxi_graph();
+    let unknown = RouteLocation::Stand(String::from("999"));
+
+    assert_eq!(
+        graph.route(&unknown, &HOLDING_POINT).err(),
+        Some(RouteError::UnknownLocation(unknown))
+    );
+}
@@ -0,0 +1,46 @@
+use amdb_geo::parse_airport;
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+#[test]
+fn groups_features_by_designator() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let designators: Vec<_> = airport
+        .taxiways()
+        .iter()
+        .map(|taxiway| taxiway.designator.as_str())
+        .collect();
+    assert_eq!(designators, ["A", "B", "K", "E"]);
+
+    let taxiway = airport.taxiway("A").unwrap();
+
+    assert_eq!(taxiway.element_ids, [9]);
+    assert_eq!(taxiway.guidance_line_ids, [13, 14]);
+    assert_eq!(taxiway.exit_line_ids, [5]);
+    assert_eq!(taxiway.holding_position_ids, [18]);
+    assert_eq!(taxiway.centerline.0.len(), 1);
+}
+
+#[test]
+fn finds_connected_runways_and_aprons() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    let connected_runways = |designator: &str| -> Vec<String> {
+        let taxiway = airport.taxiway(designator).unwrap();
+        taxiway
+            .connected_runways(&airport)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    };
+    let connected_aprons = |designator: &str| airport.taxiway(designator).unwrap().connected_aprons(&airport).to_vec();
+
+    assert_eq!(connected_runways("A"), ["09.27"]);
+    assert!(connected_runways("K").is_empty());
+
+    assert!(connected_aprons("A").is_empty());
+    assert_eq!(connected_aprons("B"), ["A1"]);
+    assert_eq!(connected_aprons("E"), ["A1"]);
+    assert!(connected_aprons("K").is_empty());
+}
@@ -0,0 +1,86 @@
+use amdb_geo::{parse_airport, parse_airport_with_options, Airport, ParseOptions, Winding};
+use geo::{Polygon, Winding as _};
+use serde_json::{json, Value};
+
+const AIRPORT: &str = include_str!("fixtures/airport.json");
+
+fn parse_with_winding(document: &str, winding: Option<Winding>) -> Airport {
+    let options = ParseOptions {
+        winding,
+        ..ParseOptions::default()
+    };
+    let (airport, _) = parse_airport_with_options(document, options).unwrap();
+
+    airport
+}
+
+/// The fixture with a hole wound clockwise, like its exterior, cut out of the apron, whose exterior ring is left open
+fn apron_with_hole() -> String {
+    let mut document: Value = serde_json::from_str(AIRPORT).unwrap();
+    document["apronelement"]["features"][0]["geometry"]["coordinates"] = json!([
+        [[0.013, 0.0012], [0.013, 0.00315], [0.017, 0.00315], [0.017, 0.0012]],
+        [
+            [0.014, 0.0014],
+            [0.014, 0.0016],
+            [0.016, 0.0016],
+            [0.016, 0.0014],
+            [0.014, 0.0014]
+        ]
+    ]);
+
+    document.to_string()
+}
+
+fn apron(airport: &Airport) -> &Polygon { &airport.apron_elements[0].geometry.0[0] }
+
+#[test]
+fn winding_is_kept_by_default() {
+    let airport = parse_airport(AIRPORT).unwrap();
+
+    // The runway element of the fixture is wound counter-clockwise, and every other polygon clockwise
+    assert!(airport.runway_elements[0].geometry.0[0].exterior().is_ccw());
+    assert!(airport.taxiway_elements[0].geometry.0[0].exterior().is_cw());
+    assert!(airport.runway_elements.iter().all(|element| !element.reoriented));
+    assert!(airport.taxiway_elements.iter().all(|element| !element.reoriented));
+}
+
+#[test]
+fn winding_is_normalized_when_requested() {
+    let airport = parse_with_winding(AIRPORT, Some(Winding::Clockwise));
+
+    assert!(airport.runway_elements[0].geometry.0[0].exterior().is_cw());
+    assert!(airport.runway_elements[0].reoriented);
+    assert!(airport.taxiway_elements.iter().all(|element| !element.reoriented));
+
+    let airport = parse_with_winding(AIRPORT, Some(Winding::CounterClockwise));
+
+    assert!(!airport.runway_elements[0].reoriented);
+    assert!(airport
+        .taxiway_elements
+        .iter()
+        .all(|element| element.reoriented && element.geometry.0[0].exterior().is_ccw()));
+}
+
+#[test]
+fn holes_are_wound_against_the_exterior() {
+    let airport = parse_with_winding(&apron_with_hole(), Some(Winding::Clockwise));
+
+    assert!(apron(&airport).exterior().is_cw());
+    assert!(apron(&airport).interiors()[0].is_ccw());
+    assert!(airport.apron_elements[0].reoriented);
+
+    let airport = parse_with_winding(&apron_with_hole(), Some(Winding::CounterClockwise));
+
+    assert!(apron(&airport).exterior().is_ccw());
+    assert!(apron(&airport).interiors()[0].is_cw());
+}
+
+#[test]
+fn open_rings_are_closed_whatever_the_winding() {
+    for winding in [None, Some(Winding::Clockwise), Some(Winding::CounterClockwise)] {
+        let airport = parse_with_winding(&apron_with_hole(), winding);
+
+        assert!(apron(&airport).exterior().is_closed());
+        assert_eq!(apron(&airport).exterior().0.len(), 5);
+    }
+}